@@ -1,24 +1,30 @@
-use clap::{Arg, Command};
+use clap::{error::ErrorKind, Arg, ArgAction, Command};
 use ratatui::{
     backend::CrosstermBackend,
-    layout::{Alignment, Constraint, Direction, Layout},
+    layout::{Alignment, Constraint, Direction, Flex, Layout},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
-    Terminal,
+    layout::Position,
+    widgets::{Block, BorderType, Borders, Cell, Clear, Paragraph, Row, Table},
+    Terminal, TerminalOptions, Viewport,
 };
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet, VecDeque},
     error::Error,
-    io,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
     time::{Duration, Instant},
 };
 use serde::{Deserialize, Serialize};
-use tokio;
+use chrono::{Datelike, DateTime, Local, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
 
 
 // data models
@@ -32,35 +38,208 @@ struct Competition {
     status: Status,
     #[serde(default)]
     broadcasts: Vec<Broadcast>,
+    #[serde(default)]
+    details: Vec<PlayDetail>,
+    // the session on a race weekend
+    #[serde(rename = "type", default)]
+    competition_type: Option<CompetitionType>,
+    #[serde(default)]
+    round: Option<Round>,
+    #[serde(rename = "cardSegment", default)]
+    card_segment: Option<CardSegment>,
+    // a playoff series, on every game of it
+    #[serde(default)]
+    series: Option<Series>,
+    #[serde(default)]
+    notes: Vec<Note>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Round {
+    #[serde(rename = "displayName")]
+    display_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CompetitionType {
+    #[serde(default)]
+    abbreviation: Option<String>,
+    // the weight class of a fight
+    #[serde(default)]
+    text: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Series {
+    // "playoff", mlb and nhl also send the regular season's series as "season"
+    #[serde(rename = "type", default)]
+    series_type: String,
+    #[serde(default)]
+    title: String,
+    // "CLE leads series 3-2"
+    #[serde(default)]
+    summary: String,
+    #[serde(default)]
+    completed: bool,
+    #[serde(rename = "totalCompetitions", default)]
+    total_competitions: u32,
+    #[serde(default)]
+    competitors: Vec<SeriesCompetitor>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SeriesCompetitor {
+    id: String,
+    #[serde(default)]
+    wins: u32,
+}
+
+// "AFC Wild Card Playoffs", on postseason games that aren't a series
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Note {
+    #[serde(default)]
+    headline: String,
+}
+
+// main card, prelims, ... on a fight night
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CardSegment {
+    description: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct Competitor {
+    // golf and other individual sports send an athlete instead
+    #[serde(default)]
     team: Team,
+    #[serde(default)]
+    athlete: Option<Athlete>,
+    #[serde(default)]
     score: String,
-    #[serde(rename = "homeAway")]
+    #[serde(rename = "homeAway", default)]
     home_away: String,
     #[serde(default)]
+    order: Option<u32>,
+    #[serde(default)]
+    linescores: Vec<LineScore>,
+    #[serde(default)]
+    statistics: Vec<Statistic>,
+    #[serde(default)]
+    seed: Option<Seed>,
+    // serving, in tennis
+    #[serde(default)]
+    possession: bool,
+    #[serde(default)]
+    winner: bool,
+    // through to the next round of a knockout cup
+    #[serde(default)]
+    advance: bool,
+    // the game score of a tennis match, split off `score` once that holds sets
+    #[serde(skip)]
+    points: Option<String>,
+    #[serde(default)]
     records: Vec<Record>,
+    #[serde(default)]
+    hits: Option<u32>,
+    #[serde(rename = "curatedRank", default)]
+    curated_rank: Option<CuratedRank>,
+    #[serde(rename = "shootoutScore", default)]
+    shootout_score: Option<f64>,
+}
+
+// espn sends seeds as either numbers or strings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum Seed {
+    Number(u32),
+    Text(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Statistic {
+    name: String,
+    #[serde(rename = "displayValue", default)]
+    display_value: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+struct CuratedRank {
+    current: u32,
+}
+
+impl Competitor {
+    // ap top 25, espn sends 99 for unranked teams
+    fn rank(&self) -> Option<u32> {
+        self.curated_rank.as_ref()
+            .map(|rank| rank.current)
+            .filter(|rank| (1..=25).contains(rank))
+    }
+
+    fn seed(&self) -> Option<String> {
+        match self.seed.as_ref()? {
+            Seed::Number(seed) => Some(seed.to_string()),
+            Seed::Text(seed) if !seed.is_empty() => Some(seed.clone()),
+            Seed::Text(_) => None,
+        }
+    }
+
+    // the team's abbreviation, or the player's name in individual sports
+    fn short_name(&self) -> &str {
+        match &self.athlete {
+            Some(athlete) if self.team.abbreviation.is_empty() => athlete.short_name.as_deref().unwrap_or(&athlete.display_name),
+            _ => &self.team.abbreviation,
+        }
+    }
+
+    fn display_name(&self) -> &str {
+        match &self.athlete {
+            Some(athlete) if self.team.display_name.is_empty() => &athlete.display_name,
+            _ => &self.team.display_name,
+        }
+    }
+
+    // race stats like "behindTime" or "lapsCompleted"
+    fn stat(&self, name: &str) -> Option<&str> {
+        self.statistics.iter()
+            .find(|stat| stat.name == name && !stat.display_value.is_empty())
+            .map(|stat| stat.display_value.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 struct Team {
+    #[serde(default)]
+    id: String,
     #[serde(rename = "displayName")]
     display_name: String,
     #[serde(rename = "shortDisplayName")]
     short_display_name: String,
     abbreviation: String,
     color: Option<String>,
+    #[serde(rename = "alternateColor")]
+    alternate_color: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct Status {
     #[serde(rename = "type")]
     status_type: StatusType,
-    #[serde(rename = "displayClock")]
+    #[serde(rename = "displayClock", default)]
     display_clock: String,
+    #[serde(default)]
+    clock: f64,
     period: u32,
+    // how a fight ended
+    #[serde(default)]
+    result: Option<StatusResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StatusResult {
+    #[serde(rename = "displayName", default)]
+    display_name: Option<String>,
+    #[serde(rename = "shortDisplayName", default)]
+    short_display_name: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -74,12 +253,77 @@ struct StatusType {
     short_detail: String,
 }
 
+// a round for golfers with a linescore per hole played, or a set in tennis
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LineScore {
+    #[serde(default)]
+    value: Option<f64>,
+    #[serde(rename = "displayValue", default)]
+    display_value: Option<String>,
+    #[serde(default)]
+    period: Option<u32>,
+    // points in a tennis tiebreak
+    #[serde(default)]
+    tiebreak: Option<u32>,
+    #[serde(default)]
+    linescores: Vec<LineScore>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct Record {
     name: String,
     summary: String,
 }
 
+// goals and cards, only soccer scoreboards send these
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PlayDetail {
+    #[serde(rename = "type", default)]
+    detail_type: Option<DetailType>,
+    #[serde(default)]
+    clock: Option<DetailClock>,
+    #[serde(default)]
+    team: Option<TeamRef>,
+    #[serde(rename = "scoringPlay", default)]
+    scoring_play: bool,
+    #[serde(rename = "redCard", default)]
+    red_card: bool,
+    #[serde(rename = "yellowCard", default)]
+    yellow_card: bool,
+    #[serde(rename = "ownGoal", default)]
+    own_goal: bool,
+    #[serde(rename = "penaltyKick", default)]
+    penalty_kick: bool,
+    #[serde(default)]
+    shootout: bool,
+    #[serde(rename = "athletesInvolved", default)]
+    athletes_involved: Vec<Athlete>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DetailType {
+    text: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DetailClock {
+    #[serde(rename = "displayValue")]
+    display_value: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TeamRef {
+    id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Athlete {
+    #[serde(rename = "displayName")]
+    display_name: String,
+    #[serde(rename = "shortName", default)]
+    short_name: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct Broadcast {
     names: Vec<String>,
@@ -97,245 +341,2248 @@ struct GameEvent {
     #[serde(rename = "shortName")]
     short_name: String,
     date: String,
+    #[serde(default)]
     competitions: Vec<Competition>,
+    // tennis tournaments list their matches by draw instead
+    #[serde(default)]
+    groupings: Vec<Grouping>,
+    // the tournament and draw a tennis match was split out of
+    #[serde(skip)]
+    tournament: Option<String>,
+    // where a bout split out of a fight night sits on its card
+    #[serde(skip)]
+    bout: Option<Bout>,
+    #[serde(default)]
+    season: Option<Season>,
 }
 
-// app state
-
 #[derive(Debug, Clone)]
-struct AppState {
-    events: Vec<GameEvent>,
-    selected_league: String,
-    team_filter: Option<String>,
-    error_message: Option<String>,
-    scroll_offset: usize,
-    last_refresh: Instant,
-    is_refreshing: bool,
+struct Bout {
+    // the fight night's id and start
+    card: String,
+    card_date: String,
+    // 0 is the main event
+    position: usize,
 }
 
-impl AppState {
-    fn new(league: String, team: Option<String>) -> Self {
-        Self {
-            events: Vec::new(),
-            selected_league: league,
-            team_filter: team,
-            error_message: None,
-            scroll_offset: 0,
-            last_refresh: Instant::now(),
-            is_refreshing: false,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Season {
+    // 2 is the regular season, 3 the postseason
+    #[serde(rename = "type", default)]
+    season_type: u32,
+    // soccer cups name the stage, i.e. "round-of-16"
+    #[serde(default)]
+    slug: String,
+    // the year the season ends in
+    #[serde(default)]
+    year: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Grouping {
+    grouping: GroupingInfo,
+    #[serde(default)]
+    competitions: Vec<Competition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GroupingInfo {
+    #[serde(rename = "displayName")]
+    display_name: String,
+}
+
+// config
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct Config {
+    tz: Option<String>,
+    sort: SortMode,
+    group: bool,
+    layout: LayoutMode,
+    favorites: Vec<String>,
+    notify: NotifyConfig,
+    rules: Vec<AlertRule>,
+    bar: BarConfig,
+    theme: Option<String>,
+    themes: HashMap<String, ThemeConfig>,
+    team_colors: Option<bool>,
+    colors: Option<String>,
+    keys: KeysConfig,
+    spoilers: SpoilerConfig,
+    delay: Option<DelayConfig>,
+}
+
+impl Config {
+    fn load(path: Option<&Path>) -> Result<Self, Box<dyn Error>> {
+        // an explicit --config must exist, the default location is optional
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => match default_config_path() {
+                Some(path) if path.exists() => path,
+                _ => return Ok(Config::default()),
+            },
+        };
+
+        let contents = fs::read_to_string(&path)
+            .map_err(|e| format!("could not read config {}: {}", path.display(), e))?;
+        let config = toml::from_str(&contents)
+            .map_err(|e| format!("invalid config {}: {}", path.display(), e))?;
+        Ok(config)
+    }
+}
+
+fn default_config_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("scrbrd").join("config.toml"))
+}
+
+// themes
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColorSupport {
+    TrueColor,
+    Ansi256,
+    Ansi16,
+    NoColor,
+}
+
+impl ColorSupport {
+    fn detect() -> Self {
+        // https://no-color.org
+        if std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+            return ColorSupport::NoColor;
+        }
+        if let Ok(colorterm) = std::env::var("COLORTERM")
+            && (colorterm == "truecolor" || colorterm == "24bit") {
+            return ColorSupport::TrueColor;
+        }
+        match std::env::var("TERM") {
+            Ok(term) if term == "dumb" => ColorSupport::NoColor,
+            Ok(term) if term.contains("256color") => ColorSupport::Ansi256,
+            _ => ColorSupport::Ansi16,
         }
     }
 
-    fn get_filtered_events(&self) -> Vec<&GameEvent> {
-        if let Some(ref filter) = self.team_filter {
-            let filter_lower = filter.to_lowercase();
-            self.events.iter()
-                .filter(|event| {
-                    event.competitions.iter().any(|comp| {
-                        comp.competitors.iter().any(|competitor| {
-                            competitor.team.display_name.to_lowercase().contains(&filter_lower) ||
-                            competitor.team.short_display_name.to_lowercase().contains(&filter_lower) ||
-                            competitor.team.abbreviation.to_lowercase().contains(&filter_lower)
-                        })
-                    })
-                })
-                .collect()
-        } else {
-            self.events.iter().collect()
+    fn parse(value: &str) -> Result<Self, Box<dyn Error>> {
+        match value.to_lowercase().as_str() {
+            "auto" => Ok(ColorSupport::detect()),
+            "truecolor" | "24bit" => Ok(ColorSupport::TrueColor),
+            "256" => Ok(ColorSupport::Ansi256),
+            "16" => Ok(ColorSupport::Ansi16),
+            "none" | "off" => Ok(ColorSupport::NoColor),
+            _ => Err(format!("unsupported colors: {} (auto, truecolor, 256, 16, none)", value).into()),
         }
     }
 
-    fn scroll_up(&mut self) {
-        if self.scroll_offset > 0 {
-            self.scroll_offset -= 1;
+    fn downsample(self, color: Color) -> Color {
+        match (self, color) {
+            (ColorSupport::Ansi256, Color::Rgb(r, g, b)) => Color::Indexed(rgb_to_ansi256(r, g, b)),
+            (ColorSupport::Ansi16, Color::Rgb(r, g, b)) => rgb_to_ansi16(r, g, b),
+            (ColorSupport::Ansi16, Color::Indexed(index)) => {
+                let (r, g, b) = ansi256_to_rgb(index);
+                rgb_to_ansi16(r, g, b)
+            }
+            _ => color,
         }
     }
 
-    fn scroll_down(&mut self) {
-        let total_games = self.get_filtered_events().len();
-        if self.scroll_offset + 1 < total_games {
-            self.scroll_offset += 1;
+    fn adapt(self, style: Style) -> Style {
+        Style {
+            fg: style.fg.map(|color| self.downsample(color)),
+            bg: style.bg.map(|color| self.downsample(color)),
+            ..style
         }
     }
 }
 
-// data fetching
+// the six steps of the xterm color cube
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
 
-impl AppState {
-    async fn fetch_data(&mut self) -> Result<(), Box<dyn Error>> {
-        self.is_refreshing = true;
-        
-        let sport_code = get_sport_code(&self.selected_league)?;
-        let url = format!("https://site.api.espn.com/apis/site/v2/sports/{}/scoreboard", sport_code);
-        
-        let client = reqwest::Client::new();
-        let response = client
-            .get(&url)
-            .header("User-Agent", "scrbrd/0.2.0")
-            .send()
-            .await?;
+// xterm's defaults for the 16 basic colors
+const ANSI16: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
 
-        if !response.status().is_success() {
-            self.is_refreshing = false;
-            return Err(format!("ESPN API error: {}", response.status()).into());
-        }
+fn color_distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| (x as i32 - y as i32).pow(2) as u32;
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
 
-        let espn_data: EspnResponse = response.json().await?;
-        self.events = espn_data.events;
-        self.error_message = None;
-        self.last_refresh = Instant::now();
-        self.is_refreshing = false;
-        
-        Ok(())
-    }
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let nearest_level = |value: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&i| (CUBE_LEVELS[i] as i32 - value as i32).abs())
+            .unwrap_or(0) as u8
+    };
+    let (ri, gi, bi) = (nearest_level(r), nearest_level(g), nearest_level(b));
+    let cube = 16 + 36 * ri + 6 * gi + bi;
 
-    fn should_refresh(&self) -> bool {
-        self.last_refresh.elapsed() >= Duration::from_secs(30)
+    // the grayscale ramp is finer than the cube for anything close to gray
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let gray = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+
+    if color_distance((r, g, b), ansi256_to_rgb(gray)) < color_distance((r, g, b), ansi256_to_rgb(cube)) {
+        gray
+    } else {
+        cube
     }
+}
 
-    fn time_until_next_refresh(&self) -> Duration {
-        let elapsed = self.last_refresh.elapsed();
-        if elapsed >= Duration::from_secs(30) {
-            Duration::from_secs(0)
-        } else {
-            Duration::from_secs(30) - elapsed
+fn ansi256_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => ANSI16[index as usize].1,
+        16..=231 => {
+            let index = index - 16;
+            (
+                CUBE_LEVELS[(index / 36) as usize],
+                CUBE_LEVELS[(index / 6 % 6) as usize],
+                CUBE_LEVELS[(index % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + 10 * (index - 232);
+            (level, level, level)
         }
     }
 }
 
-fn get_sport_code(league: &str) -> Result<&'static str, Box<dyn Error>> {
-    match league.to_lowercase().as_str() {
-        "mlb" => Ok("baseball/mlb"),
-        "nba" => Ok("basketball/nba"),
-        "wnba" => Ok("basketball/wnba"), 
-        "nfl" => Ok("football/nfl"),
-        "nhl" => Ok("hockey/nhl"),
-        "mls" => Ok("soccer/usa.1"),
-        "nwsl" => Ok("soccer/usa.nwsl"),
-        "premier" | "epl" | "prem" | "premier-league" => Ok("soccer/eng.1"),
-        _ => Err(format!("unsupported league: {}", league).into()),
+fn rgb_to_ansi16(r: u8, g: u8, b: u8) -> Color {
+    ANSI16.iter()
+        .min_by_key(|(_, rgb)| color_distance((r, g, b), *rgb))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::Reset)
+}
+
+// espn sends team colors as bare hex, i.e. "0c2340"
+fn parse_team_color(hex: &str) -> Option<(u8, u8, u8)> {
+    let hex = hex.trim_start_matches('#');
+    // the slices below are by byte, so anything past ascii would split a character
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
     }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some((channel(0)?, channel(2)?, channel(4)?))
 }
 
-// score block formatting
+fn luminance((r, g, b): (u8, u8, u8)) -> f32 {
+    (0.2126 * r as f32 + 0.7152 * g as f32 + 0.0722 * b as f32) / 255.0
+}
 
-impl AppState {
-    fn format_game_widget(&self, event: &GameEvent) -> Paragraph {
-        let mut content = Vec::new();
+#[derive(Debug, Clone)]
+struct Theme {
+    title: Style,
+    text: Style,
+    score: Style,
+    dim: Style,
+    live: Style,
+    finished: Style,
+    upcoming: Style,
+    error: Style,
+    accent: Style,
+    flash: Style,
+    highlight: Style,
+    border: Style,
+    light: bool,
+    team_colors: bool,
+    colors: ColorSupport,
+}
 
-        for competition in &event.competitions {
-            if competition.competitors.len() >= 2 {
-                let away = &competition.competitors[0];
-                let home = &competition.competitors[1];
-                
-                // score line
-                let score_line = format!(
-                    "{} {} - {} {}",
-                    away.team.abbreviation,
-                    away.score,
-                    home.score,
-                    home.team.abbreviation
-                );
-                
-                let score_style = Style::default().fg(Color::White).add_modifier(Modifier::BOLD);
-                content.push(Line::from(vec![
-                    Span::styled(score_line, score_style)
-                ]).alignment(Alignment::Center));
-                
-                // status line
-                let status_line = self.format_status(&competition.status);
-                if !status_line.is_empty() {
-                    let status_style = get_status_style(&status_line);
-                    content.push(Line::from(vec![
-                        Span::styled(status_line, status_style)
-                    ]).alignment(Alignment::Center));
-                    content.push(Line::from(""));
-                }
+const THEME_NAMES: &str = "dark, light, high-contrast, monochrome";
 
-                // records line
-                add_records_line(&mut content, away, home);
-            }
+impl Theme {
+    fn dark() -> Self {
+        let plain = Style::default();
+        Self {
+            title: plain.fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            text: plain.fg(Color::White),
+            score: plain.fg(Color::White).add_modifier(Modifier::BOLD),
+            dim: plain.fg(Color::Gray),
+            live: plain.fg(Color::Red).add_modifier(Modifier::BOLD),
+            finished: plain.fg(Color::Green),
+            upcoming: plain.fg(Color::Yellow),
+            error: plain.fg(Color::Red),
+            accent: plain.fg(Color::Yellow),
+            flash: plain.fg(Color::Black).bg(Color::Yellow).add_modifier(Modifier::BOLD),
+            highlight: plain.fg(Color::Magenta).add_modifier(Modifier::BOLD),
+            border: plain,
+            light: false,
+            team_colors: true,
+            colors: ColorSupport::TrueColor,
         }
+    }
 
-        Paragraph::new(content)
-            .block(Block::default().borders(Borders::ALL))
-            .alignment(Alignment::Center)
+    fn light() -> Self {
+        let plain = Style::default();
+        Self {
+            title: plain.fg(Color::Blue).add_modifier(Modifier::BOLD),
+            text: plain.fg(Color::Black),
+            score: plain.fg(Color::Black).add_modifier(Modifier::BOLD),
+            dim: plain.fg(Color::DarkGray),
+            live: plain.fg(Color::Red).add_modifier(Modifier::BOLD),
+            finished: plain.fg(Color::Green),
+            upcoming: plain.fg(Color::Blue),
+            error: plain.fg(Color::Red),
+            accent: plain.fg(Color::Blue),
+            flash: plain.fg(Color::White).bg(Color::Blue).add_modifier(Modifier::BOLD),
+            highlight: plain.fg(Color::Magenta).add_modifier(Modifier::BOLD),
+            border: plain.fg(Color::DarkGray),
+            light: true,
+            ..Theme::dark()
+        }
     }
 
-    fn format_status(&self, status: &Status) -> String {
-        match status.status_type.state.as_str() {
-            "pre" => status.status_type.short_detail.clone(),
-            "in" => format!("🔴 LIVE | {}", self.format_live_status(status)),
-            "post" => {
-                if status.status_type.completed {
-                    "FINAL".to_string()
-                } else {
-                    status.status_type.short_detail.clone()
-                }
-            },
-            _ => status.status_type.short_detail.clone(),
+    fn high_contrast() -> Self {
+        let plain = Style::default();
+        let bold = plain.add_modifier(Modifier::BOLD);
+        Self {
+            title: bold.fg(Color::LightYellow),
+            text: plain.fg(Color::White),
+            score: bold.fg(Color::White),
+            dim: plain.fg(Color::White),
+            live: bold.fg(Color::LightRed),
+            finished: bold.fg(Color::LightGreen),
+            upcoming: bold.fg(Color::LightYellow),
+            error: bold.fg(Color::LightRed),
+            accent: bold.fg(Color::LightCyan),
+            flash: bold.fg(Color::Black).bg(Color::LightYellow),
+            highlight: bold.fg(Color::LightMagenta),
+            border: plain.fg(Color::White),
+            ..Theme::dark()
         }
     }
 
-    fn format_live_status(&self, status: &Status) -> String {
-        match self.selected_league.to_lowercase().as_str() {
-            "nfl" | "football" => format_football_status(status),
-            "nba" | "wnba" | "basketball" => format_basketball_status(status),
-            "nhl" | "hockey" => format_hockey_status(status),
-            "mlb" | "baseball" => format_baseball_status(status),
-            "mls" | "nwsl" | "premier" | "epl" | "soccer" => format_soccer_status(status),
-            _ => format!("{} - {}", status.period, status.display_clock),
+    // modifiers only, for terminals without color or NO_COLOR
+    fn monochrome() -> Self {
+        let plain = Style::default();
+        let bold = plain.add_modifier(Modifier::BOLD);
+        Self {
+            title: bold,
+            text: plain,
+            score: bold,
+            dim: plain.add_modifier(Modifier::DIM),
+            live: bold,
+            finished: plain,
+            upcoming: plain,
+            error: bold,
+            accent: bold,
+            flash: bold.add_modifier(Modifier::REVERSED),
+            highlight: bold.add_modifier(Modifier::UNDERLINED),
+            border: plain,
+            team_colors: false,
+            ..Theme::dark()
         }
     }
-}
 
-fn add_records_line(content: &mut Vec<Line>, away: &Competitor, home: &Competitor) {
-    if !away.records.is_empty() || !home.records.is_empty() {
-        let away_record = away.records.first()
-            .map(|r| r.summary.clone())
-            .unwrap_or_default();
-        let home_record = home.records.first()
-            .map(|r| r.summary.clone())
-            .unwrap_or_default();
-        
-        if !away_record.is_empty() || !home_record.is_empty() {
-            let record_line = format!("({}) vs ({})", away_record, home_record);
-            content.push(Line::from(vec![
-                Span::styled(record_line, Style::default().fg(Color::Gray))
-            ]).alignment(Alignment::Center));
+    fn builtin(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "dark" => Some(Theme::dark()),
+            "light" => Some(Theme::light()),
+            "high-contrast" | "contrast" => Some(Theme::high_contrast()),
+            "monochrome" | "mono" => Some(Theme::monochrome()),
+            _ => None,
         }
     }
-}
 
-fn get_status_style(status: &str) -> Style {
-    if status.contains("LIVE") {
-        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
-    } else if status.contains("FINAL") {
-        Style::default().fg(Color::Green)
-    } else {
-        Style::default().fg(Color::Yellow)
+    fn from_config(config: &Config, name: Option<&str>) -> Result<Self, Box<dyn Error>> {
+        let colors = match config.colors.as_deref() {
+            Some(colors) => ColorSupport::parse(colors)?,
+            None => ColorSupport::detect(),
+        };
+        let name = name.or(config.theme.as_deref()).unwrap_or("dark");
+
+        let mut theme = if colors == ColorSupport::NoColor {
+            Theme::monochrome()
+        } else {
+            Theme::resolve(name, &config.themes, 0)?
+        };
+        if config.team_colors == Some(false) {
+            theme.team_colors = false;
+        }
+        theme.colors = colors;
+        theme.adapt_styles();
+        Ok(theme)
     }
-}
 
-// sport-specific formatting
+    // user themes build on a base, which can be another user theme
+    fn resolve(name: &str, themes: &HashMap<String, ThemeConfig>, depth: usize) -> Result<Self, Box<dyn Error>> {
+        let Some(custom) = themes.get(name) else {
+            return Theme::builtin(name)
+                .ok_or_else(|| format!("unknown theme: {} ({} or a [themes.<name>] table)", name, THEME_NAMES).into());
+        };
+        if depth > themes.len() {
+            return Err(format!("theme {} has a circular base", name).into());
+        }
 
-fn format_football_status(status: &Status) -> String {
-    match status.period {
-        1..=4 => format!("Q{}", status.period),
-        5 => "OT".to_string(),
-        _ => format!("Q{}", status.period),
+        let mut theme = Theme::resolve(custom.base.as_deref().unwrap_or("dark"), themes, depth + 1)?;
+        let slots = [
+            (&custom.title, &mut theme.title),
+            (&custom.text, &mut theme.text),
+            (&custom.score, &mut theme.score),
+            (&custom.dim, &mut theme.dim),
+            (&custom.live, &mut theme.live),
+            (&custom.finished, &mut theme.finished),
+            (&custom.upcoming, &mut theme.upcoming),
+            (&custom.error, &mut theme.error),
+            (&custom.accent, &mut theme.accent),
+            (&custom.highlight, &mut theme.highlight),
+            (&custom.border, &mut theme.border),
+        ];
+        for (value, style) in slots {
+            if let Some(value) = value {
+                *style = style.fg(parse_theme_color(name, value)?);
+            }
+        }
+        if let Some(ref value) = custom.flash {
+            theme.flash = theme.flash.bg(parse_theme_color(name, value)?);
+        }
+        if let Some(light) = custom.light {
+            theme.light = light;
+        }
+        Ok(theme)
     }
-}
 
-fn format_basketball_status(status: &Status) -> String {
-    match status.period {
-        1..=4 => format!("Q{}", status.period),
-        5.. => "OT".to_string(),
-        _ => format!("Q{}", status.period),
+    fn adapt_styles(&mut self) {
+        let colors = self.colors;
+        for style in [
+            &mut self.title, &mut self.text, &mut self.score, &mut self.dim,
+            &mut self.live, &mut self.finished, &mut self.upcoming, &mut self.error,
+            &mut self.accent, &mut self.flash, &mut self.highlight, &mut self.border,
+        ] {
+            *style = colors.adapt(*style);
+        }
+    }
+
+    // primary color unless it disappears into the background, then the alternate
+    fn team_color(&self, team: &Team) -> Option<Color> {
+        if !self.team_colors {
+            return None;
+        }
+        [&team.color, &team.alternate_color].into_iter()
+            .flatten()
+            .filter_map(|hex| parse_team_color(hex))
+            .find(|&rgb| if self.light { luminance(rgb) < 0.75 } else { luminance(rgb) > 0.2 })
+            .map(|(r, g, b)| self.colors.downsample(Color::Rgb(r, g, b)))
     }
 }
 
-fn format_hockey_status(status: &Status) -> String {
+fn parse_theme_color(theme: &str, value: &str) -> Result<Color, Box<dyn Error>> {
+    value.parse::<Color>()
+        .map_err(|_| format!("invalid color in theme {}: {} (a name, #rrggbb or 0-255)", theme, value).into())
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct ThemeConfig {
+    base: Option<String>,
+    light: Option<bool>,
+    title: Option<String>,
+    text: Option<String>,
+    score: Option<String>,
+    dim: Option<String>,
+    live: Option<String>,
+    finished: Option<String>,
+    upcoming: Option<String>,
+    error: Option<String>,
+    accent: Option<String>,
+    flash: Option<String>,
+    highlight: Option<String>,
+    border: Option<String>,
+}
+
+// app state
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum SortMode {
+    #[default]
+    Status,
+    #[serde(alias = "time")]
+    StartTime,
+    Favorites,
+    #[serde(alias = "close")]
+    Closeness,
+    Espn,
+}
+
+impl SortMode {
+    fn next(self) -> Self {
+        match self {
+            SortMode::Status => SortMode::StartTime,
+            SortMode::StartTime => SortMode::Favorites,
+            SortMode::Favorites => SortMode::Closeness,
+            SortMode::Closeness => SortMode::Espn,
+            SortMode::Espn => SortMode::Status,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            SortMode::Status => "status",
+            SortMode::StartTime => "start time",
+            SortMode::Favorites => "favorites",
+            SortMode::Closeness => "closeness",
+            SortMode::Espn => "espn",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum LayoutMode {
+    #[default]
+    Cards,
+    Compact,
+}
+
+impl LayoutMode {
+    fn parse(value: &str) -> Result<Self, Box<dyn Error>> {
+        match value.to_lowercase().as_str() {
+            "cards" => Ok(LayoutMode::Cards),
+            "compact" | "table" => Ok(LayoutMode::Compact),
+            _ => Err(format!("unsupported layout: {} (cards, compact)", value).into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum GameGroup {
+    Live,
+    Upcoming,
+    Final,
+}
+
+impl GameGroup {
+    fn of(event: &GameEvent) -> Self {
+        current_competition(event).map_or(GameGroup::Upcoming, GameGroup::of_competition)
+    }
+
+    fn of_competition(competition: &Competition) -> Self {
+        match competition.status.status_type.state.as_str() {
+            "in" => GameGroup::Live,
+            "post" => GameGroup::Final,
+            _ => GameGroup::Upcoming,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            GameGroup::Live => "live",
+            GameGroup::Upcoming => "upcoming",
+            GameGroup::Final => "final",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum TimeZoneSetting {
+    Local,
+    Named(Tz),
+}
+
+impl TimeZoneSetting {
+    fn parse(value: &str) -> Result<Self, Box<dyn Error>> {
+        if value.eq_ignore_ascii_case("local") {
+            return Ok(TimeZoneSetting::Local);
+        }
+        Tz::from_str_insensitive(value)
+            .map(TimeZoneSetting::Named)
+            .map_err(|_| format!("unknown timezone: {} (use an iana name like america/los_angeles)", value).into())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AppState {
+    events: Vec<GameEvent>,
+    leagues: Vec<String>,
+    selected_league: String,
+    team_filter: Option<String>,
+    conference: Option<String>,
+    top25: bool,
+    timezone: TimeZoneSetting,
+    theme: Theme,
+    keymap: Keymap,
+    sort_mode: SortMode,
+    group_games: bool,
+    layout_mode: LayoutMode,
+    grid_columns: usize,
+    page_size: usize,
+    favorites: Vec<String>,
+    notify: NotifyConfig,
+    rules: Vec<AlertRule>,
+    // (event id, rule index) for every rule matching a game right now
+    rule_matches: HashSet<(String, usize)>,
+    spoilers: SpoilerConfig,
+    revealed: HashSet<String>,
+    search_query: String,
+    search_active: bool,
+    selected: Option<String>,
+    detail_open: bool,
+    show_help: bool,
+    show_bracket: bool,
+    // fetched when the bracket is first shown, none until then
+    bracket: Option<Vec<BracketSeries>>,
+    // kept apart from error_message, which takes over the games view too
+    bracket_error: Option<String>,
+    click_targets: Vec<(ratatui::layout::Rect, ClickTarget)>,
+    flashes: HashMap<String, Flash>,
+    event_log: Vec<LogEntry>,
+    show_log: bool,
+    log_scroll: usize,
+    error_message: Option<String>,
+    scroll_offset: usize,
+    last_refresh: Instant,
+    is_refreshing: bool,
+    delay: StreamDelay,
+    pending: VecDeque<(Instant, Vec<GameEvent>)>,
+}
+
+impl AppState {
+    fn new(leagues: Vec<String>, team: Option<String>, timezone: TimeZoneSetting, theme: Theme, keymap: Keymap, delay: StreamDelay, config: &Config) -> Self {
+        Self {
+            events: Vec::new(),
+            selected_league: leagues[0].clone(),
+            leagues,
+            team_filter: team,
+            conference: None,
+            top25: false,
+            timezone,
+            theme,
+            keymap,
+            sort_mode: config.sort,
+            group_games: config.group,
+            layout_mode: config.layout,
+            grid_columns: 1,
+            page_size: 1,
+            favorites: config.favorites.clone(),
+            notify: config.notify.clone(),
+            rules: config.rules.clone(),
+            rule_matches: HashSet::new(),
+            spoilers: config.spoilers.clone(),
+            revealed: HashSet::new(),
+            search_query: String::new(),
+            search_active: false,
+            selected: None,
+            detail_open: false,
+            show_help: false,
+            show_bracket: false,
+            bracket: None,
+            bracket_error: None,
+            click_targets: Vec::new(),
+            flashes: HashMap::new(),
+            event_log: Vec::new(),
+            show_log: false,
+            log_scroll: 0,
+            error_message: None,
+            scroll_offset: 0,
+            last_refresh: Instant::now(),
+            is_refreshing: false,
+            delay,
+            pending: VecDeque::new(),
+        }
+    }
+
+    fn get_filtered_events(&self) -> Vec<&GameEvent> {
+        let mut events: Vec<&GameEvent> = self.events.iter()
+            .filter(|event| match self.team_filter {
+                Some(ref filter) => event_matches_team(event, filter),
+                None => true,
+            })
+            .filter(|event| !self.top25 || is_top25_game(event))
+            .filter(|event| self.matches_search(event))
+            .collect();
+
+        self.sort_events(&mut events);
+        events
+    }
+
+    fn sort_events(&self, events: &mut [&GameEvent]) {
+        // a fight card reads from the main event down, whatever each bout's state
+        let fight = self.is_fight();
+        let by_status = |a: &GameEvent, b: &GameEvent| if fight { card_order(a).cmp(&card_order(b)) } else { compare_by_status(a, b) };
+        // sort_by is stable, so ties keep espn's order
+        match self.sort_mode {
+            SortMode::Espn => {}
+            SortMode::Status => events.sort_by(|a, b| by_status(a, b)),
+            SortMode::StartTime if fight => events.sort_by(|a, b| by_status(a, b)),
+            SortMode::StartTime => events.sort_by_key(|event| parse_event_date(&event.date)),
+            SortMode::Favorites => events.sort_by(|a, b| {
+                self.is_favorite(b).cmp(&self.is_favorite(a))
+                    .then_with(|| by_status(a, b))
+            }),
+            // a hidden score can't give its game's place away
+            SortMode::Closeness => events.sort_by(|a, b| {
+                self.shown_margin(a).unwrap_or(u32::MAX).cmp(&self.shown_margin(b).unwrap_or(u32::MAX))
+                    .then_with(|| by_status(a, b))
+            }),
+        }
+
+        // headers need each group to be contiguous
+        if self.group_games {
+            events.sort_by_key(|event| GameGroup::of(event));
+        }
+    }
+
+    // every word of the query has to match a team or the game's status
+    // status words only match the status, "ot" shouldn't find minnesota or "live" liverpool
+    fn matches_search(&self, event: &GameEvent) -> bool {
+        self.search_query.split_whitespace().all(|term| {
+            if STATUS_KEYWORDS.contains(&term.to_lowercase().as_str()) {
+                self.event_matches_status(event, term)
+            } else {
+                event_matches_team(event, term) || self.event_matches_status(event, term)
+            }
+        })
+    }
+
+    fn event_matches_status(&self, event: &GameEvent, term: &str) -> bool {
+        let term = term.to_lowercase();
+        let group = GameGroup::of(event);
+        let group_match = match term.as_str() {
+            "live" | "in" => group == GameGroup::Live,
+            "upcoming" | "pre" | "scheduled" => group == GameGroup::Upcoming,
+            "final" | "post" => group == GameGroup::Final,
+            _ => false,
+        };
+
+        // prefix match on words like "ot", "b7" or "postponed", but not inside "bot"
+        group_match || event.competitions.iter().any(|comp| {
+            let status_text = format!("{} {}", self.format_status(comp), self.shown_detail(event, comp, &comp.status.status_type.short_detail));
+            status_text
+                .split(|c: char| !c.is_alphanumeric())
+                .any(|word| word.to_lowercase().starts_with(&term))
+        })
+    }
+
+    fn start_search(&mut self) {
+        self.search_active = true;
+    }
+
+    fn push_search_char(&mut self, c: char) {
+        self.search_query.push(c);
+        self.scroll_offset = 0;
+    }
+
+    fn pop_search_char(&mut self) {
+        self.search_query.pop();
+        self.scroll_offset = 0;
+    }
+
+    fn finish_search(&mut self) {
+        self.search_active = false;
+    }
+
+    fn clear_search(&mut self) {
+        self.search_query.clear();
+        self.search_active = false;
+        self.scroll_offset = 0;
+    }
+
+    fn is_favorite(&self, event: &GameEvent) -> bool {
+        self.favorites.iter().any(|favorite| event_matches_team(event, favorite))
+    }
+
+    fn cycle_sort_mode(&mut self) {
+        self.sort_mode = self.sort_mode.next();
+        self.scroll_offset = 0;
+    }
+
+    fn toggle_grouping(&mut self) {
+        self.group_games = !self.group_games;
+        self.scroll_offset = 0;
+    }
+
+    fn toggle_layout(&mut self) {
+        self.layout_mode = match self.layout_mode {
+            LayoutMode::Cards => LayoutMode::Compact,
+            LayoutMode::Compact => LayoutMode::Cards,
+        };
+    }
+
+    // scrolling moves a whole row of the grid at a time, or a player on a leaderboard
+    fn scroll_up(&mut self) {
+        let row_start = self.scroll_offset - self.scroll_offset % self.grid_columns;
+        self.scroll_offset = row_start.saturating_sub(self.grid_columns);
+    }
+
+    fn scroll_down(&mut self) {
+        let total_games = self.scroll_total(&self.get_filtered_events());
+        let row_start = self.scroll_offset - self.scroll_offset % self.grid_columns;
+        if row_start + self.grid_columns < total_games {
+            self.scroll_offset = row_start + self.grid_columns;
+        }
+    }
+
+    fn scroll_page_up(&mut self) {
+        let row_start = self.scroll_offset - self.scroll_offset % self.grid_columns;
+        self.scroll_offset = row_start.saturating_sub(self.page_size.max(self.grid_columns));
+    }
+
+    fn scroll_page_down(&mut self) {
+        let row_start = self.scroll_offset - self.scroll_offset % self.grid_columns;
+        self.scroll_offset = (row_start + self.page_size.max(self.grid_columns)).min(self.last_page_offset().max(row_start));
+    }
+
+    fn scroll_to_top(&mut self) {
+        self.scroll_offset = 0;
+    }
+
+    fn scroll_to_bottom(&mut self) {
+        self.scroll_offset = self.last_page_offset();
+    }
+
+    // the first row of the screen that ends on the last game
+    fn last_page_offset(&self) -> usize {
+        let total_games = self.scroll_total(&self.get_filtered_events());
+        total_games.saturating_sub(self.page_size).div_ceil(self.grid_columns) * self.grid_columns
+    }
+}
+
+const STATUS_KEYWORDS: &[&str] = &[
+    "live", "in", "upcoming", "pre", "scheduled", "final", "post",
+    "ot", "so", "halftime", "delayed", "postponed", "canceled", "suspended",
+];
+
+fn is_top25_game(event: &GameEvent) -> bool {
+    event.competitions.iter()
+        .flat_map(|comp| comp.competitors.iter())
+        .any(|competitor| competitor.rank().is_some())
+}
+
+fn event_matches_team(event: &GameEvent, filter: &str) -> bool {
+    event.competitions.iter().any(|comp| {
+        comp.competitors.iter().any(|competitor| competitor_matches(competitor, filter))
+    })
+}
+
+// golfers are matched by name
+fn competitor_matches(competitor: &Competitor, filter: &str) -> bool {
+    team_matches(&competitor.team, filter) || competitor.athlete.as_ref()
+        .is_some_and(|athlete| athlete.display_name.to_lowercase().contains(&filter.to_lowercase()))
+}
+
+fn team_matches(team: &Team, filter: &str) -> bool {
+    let filter_lower = filter.to_lowercase();
+    team.display_name.to_lowercase().contains(&filter_lower) ||
+    team.short_display_name.to_lowercase().contains(&filter_lower) ||
+    team.abbreviation.to_lowercase().contains(&filter_lower)
+}
+
+// live first, then upcoming by start time, then finals
+fn compare_by_status(a: &GameEvent, b: &GameEvent) -> Ordering {
+    let (group_a, group_b) = (GameGroup::of(a), GameGroup::of(b));
+    group_a.cmp(&group_b).then_with(|| {
+        if group_a == GameGroup::Upcoming {
+            parse_event_date(&a.date).cmp(&parse_event_date(&b.date))
+        } else {
+            Ordering::Equal
+        }
+    })
+}
+
+// fight nights by when they start, then each bout by its place on the card
+fn card_order(event: &GameEvent) -> (Option<DateTime<Utc>>, &str, usize) {
+    match &event.bout {
+        Some(bout) => (parse_event_date(&bout.card_date), bout.card.as_str(), bout.position),
+        None => (parse_event_date(&event.date), event.id.as_str(), 0),
+    }
+}
+
+// games without numeric scores yet (i.e. pregame) have no margin
+fn score_margin(event: &GameEvent) -> Option<u32> {
+    if GameGroup::of(event) == GameGroup::Upcoming {
+        return None;
+    }
+    let (away, home) = away_home(event.competitions.first()?)?;
+    let away = away.score.parse::<i64>().ok()?;
+    let home = home.score.parse::<i64>().ok()?;
+    Some(away.abs_diff(home) as u32)
+}
+
+// data fetching
+
+impl AppState {
+    async fn fetch_data(&mut self) -> Result<Vec<GameChange>, Box<dyn Error>> {
+        self.fetch_snapshot().await?;
+        self.load_bracket().await;
+        Ok(self.release_delayed().unwrap_or_default())
+    }
+
+    // the bracket covers months of games, so it's only fetched again on a manual refresh.
+    // it's only ever shown in its own view, so a failure there leaves the scores alone
+    async fn load_bracket(&mut self) {
+        if !self.show_bracket || self.bracket.is_some() || self.bracket_delayed() {
+            return;
+        }
+        match self.fetch_bracket().await {
+            Ok(bracket) => {
+                self.bracket = Some(bracket);
+                self.bracket_error = None;
+            }
+            Err(e) => self.bracket_error = Some(format!("bracket failed: {}", e)),
+        }
+    }
+
+    // the bracket can't wait out a delay like the games do, and it'd show who went through early
+    fn bracket_delayed(&self) -> bool {
+        !self.delay.for_league(&self.selected_league).is_zero()
+    }
+
+    // fetches when a refresh is due, then hands back whatever the delay lets through
+    async fn next_update(&mut self) -> Result<Option<Vec<GameChange>>, Box<dyn Error>> {
+        if self.should_refresh() || (self.events.is_empty() && self.pending.is_empty()) {
+            self.fetch_snapshot().await?;
+        }
+        Ok(self.release_delayed())
+    }
+
+    async fn fetch_snapshot(&mut self) -> Result<(), Box<dyn Error>> {
+        self.is_refreshing = true;
+        let result = self.fetch_events().await;
+        self.is_refreshing = false;
+        // a failed fetch waits out the interval too, so an outage isn't retried every frame
+        self.last_refresh = Instant::now();
+
+        self.pending.push_back((Instant::now(), result?));
+        self.error_message = None;
+        Ok(())
+    }
+
+    // applies the newest snapshot that has waited out the delay, if any
+    fn release_delayed(&mut self) -> Option<Vec<GameChange>> {
+        let delay = self.delay.for_league(&self.selected_league);
+        let mut latest = None;
+        while let Some((fetched, _)) = self.pending.front()
+            && fetched.elapsed() >= delay {
+            latest = self.pending.pop_front().map(|(_, events)| events);
+        }
+        latest.map(|events| self.apply_events(events))
+    }
+
+    fn apply_events(&mut self, events: Vec<GameEvent>) -> Vec<GameChange> {
+        let changes = detect_game_changes(&self.events, &events);
+        self.events = events;
+        self.record_game_changes(&changes);
+        self.notify_game_changes(&changes);
+        self.evaluate_rules();
+        changes
+    }
+
+    fn time_until_release(&self) -> Option<Duration> {
+        let delay = self.delay.for_league(&self.selected_league);
+        self.pending.front().map(|(fetched, _)| delay.saturating_sub(fetched.elapsed()))
+    }
+
+    // nothing to show until the first snapshot has waited out the delay
+    fn delay_message(&self) -> Option<String> {
+        self.time_until_release().map(|release| format!(
+            "{} behind live, first scores in {}s",
+            format_delay(self.delay.for_league(&self.selected_league)),
+            release.as_secs()
+        ))
+    }
+
+    fn time_until_next_update(&self) -> Duration {
+        let refresh = self.time_until_next_refresh();
+        self.time_until_release().map_or(refresh, |release| release.min(refresh))
+    }
+
+    // a one-off print has nothing queued up to show, so it holds on to what it fetched until it's
+    // as old as the delay, just like a snapshot waiting in the queue
+    async fn fetch_delayed_events(&self) -> Result<Vec<GameEvent>, Box<dyn Error>> {
+        let events = self.fetch_events().await?;
+        tokio::time::sleep(self.delay.for_league(&self.selected_league)).await;
+        Ok(events)
+    }
+
+    async fn fetch_events(&self) -> Result<Vec<GameEvent>, Box<dyn Error>> {
+        fetch_scoreboard(&self.scoreboard_url(Vec::new())?, self.is_fight()).await
+    }
+
+    // every postseason game of the season. soccer cups have no postseason, so they get the last few
+    // months through the next couple of weeks instead
+    async fn fetch_bracket(&self) -> Result<Vec<BracketSeries>, Box<dyn Error>> {
+        let soccer = matches!(find_league(&self.selected_league).map(|league| league.sport), Ok(Sport::Soccer));
+        let today = Utc::now().date_naive();
+        let mut query = if soccer {
+            vec![format!(
+                "dates={}-{}",
+                (today - chrono::Duration::days(BRACKET_DAYS_BACK)).format("%Y%m%d"),
+                (today + chrono::Duration::days(BRACKET_DAYS_AHEAD)).format("%Y%m%d")
+            )]
+        } else {
+            let year = self.events.iter()
+                .filter_map(|event| event.season.as_ref().map(|season| season.year))
+                .find(|year| *year > 0)
+                .unwrap_or(today.year());
+            vec![format!("dates={}", year), format!("seasontype={}", POSTSEASON)]
+        };
+        query.push("limit=1000".to_string());
+        let events = fetch_scoreboard(&self.scoreboard_url(query)?, self.is_fight()).await?;
+        Ok(build_bracket(&events, soccer))
+    }
+
+    fn scoreboard_url(&self, mut query: Vec<String>) -> Result<String, Box<dyn Error>> {
+        let league = find_league(&self.selected_league)?;
+        let mut url = format!("https://site.api.espn.com/apis/site/v2/sports/{}/scoreboard", league.path);
+        if let Some(group) = college_group(&self.selected_league, self.conference.as_deref())? {
+            query.push(format!("groups={}", group));
+        }
+        if !query.is_empty() {
+            url.push_str(&format!("?{}", query.join("&")));
+        }
+        Ok(url)
+    }
+
+    fn should_refresh(&self) -> bool {
+        self.last_refresh.elapsed() >= Duration::from_secs(30)
+    }
+
+    fn time_until_next_refresh(&self) -> Duration {
+        let elapsed = self.last_refresh.elapsed();
+        if elapsed >= Duration::from_secs(30) {
+            Duration::from_secs(0)
+        } else {
+            Duration::from_secs(30) - elapsed
+        }
+    }
+}
+
+// fight nights come back as one event, so `fight` splits them into a card per bout
+async fn fetch_scoreboard(url: &str, fight: bool) -> Result<Vec<GameEvent>, Box<dyn Error>> {
+    let client = reqwest::Client::new();
+    let response = client
+        .get(url)
+        .header("User-Agent", "scrbrd/0.2.0")
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(format!("ESPN API error: {}", response.status()).into());
+    }
+
+    let espn_data: EspnResponse = response.json().await?;
+    let events = espn_data.events.into_iter().flat_map(split_groupings);
+    if fight {
+        Ok(events.flat_map(split_fight_card).collect())
+    } else {
+        Ok(events.collect())
+    }
+}
+
+// stream delay
+
+// how far behind live each league is shown, for streams that lag the broadcast
+#[derive(Debug, Clone, Default)]
+struct StreamDelay {
+    all: Duration,
+    leagues: HashMap<String, Duration>,
+}
+
+// `[delay] default = "45s"` for every league, with the leagues that need their own like `nfl = "90s"`
+#[derive(Debug, Clone, Deserialize)]
+struct DelayConfig {
+    #[serde(default)]
+    default: Option<String>,
+    #[serde(flatten)]
+    leagues: HashMap<String, String>,
+}
+
+impl StreamDelay {
+    fn from_config(config: Option<&DelayConfig>, all: Option<&str>) -> Result<Self, Box<dyn Error>> {
+        let mut delay = StreamDelay::default();
+        if let Some(config) = config {
+            if let Some(value) = &config.default {
+                delay.all = parse_delay(value)?;
+            }
+            for (league, value) in &config.leagues {
+                delay.leagues.insert(league.to_lowercase(), parse_delay(value)?);
+            }
+        }
+        // --delay wins over everything in the config
+        if let Some(value) = all {
+            delay.all = parse_delay(value)?;
+            delay.leagues.clear();
+        }
+        Ok(delay)
+    }
+
+    fn for_league(&self, league: &str) -> Duration {
+        self.leagues.iter()
+            .find(|(name, _)| same_league(name, league))
+            .map_or(self.all, |(_, delay)| *delay)
+    }
+}
+
+// "45s", "2m" or plain seconds
+fn parse_delay(value: &str) -> Result<Duration, Box<dyn Error>> {
+    let value = value.trim().to_lowercase();
+    let split = value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let invalid = || format!("invalid delay: {} (i.e. 45s or 2m)", value);
+    let number: u64 = number.parse().map_err(|_| invalid())?;
+    match unit.trim() {
+        "" | "s" | "sec" | "secs" => Ok(Duration::from_secs(number)),
+        "m" | "min" | "mins" => Ok(Duration::from_secs(number * 60)),
+        _ => Err(invalid().into()),
+    }
+}
+
+fn format_delay(delay: Duration) -> String {
+    let seconds = delay.as_secs();
+    match (seconds / 60, seconds % 60) {
+        (0, seconds) => format!("{}s", seconds),
+        (minutes, 0) => format!("{}m", minutes),
+        (minutes, seconds) => format!("{}m{}s", minutes, seconds),
+    }
+}
+
+// game changes
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum ChangeKind {
+    Score,
+    LeadChange,
+    Start,
+    Final,
+}
+
+#[derive(Debug, Clone)]
+struct GameChange {
+    kind: ChangeKind,
+    event_id: String,
+    scoring_team: Option<String>,
+    away_team: String,
+    away_score: String,
+    home_team: String,
+    home_score: String,
+    competition: Competition,
+}
+
+#[derive(Debug, Clone)]
+struct Flash {
+    team: String,
+    started: Instant,
+}
+
+#[derive(Debug, Clone)]
+struct LogEntry {
+    time: DateTime<Utc>,
+    text: String,
+}
+
+// how long a card stays highlighted after its score moves
+const FLASH_DURATION: Duration = Duration::from_secs(10);
+const MAX_LOG_ENTRIES: usize = 500;
+
+fn away_home(competition: &Competition) -> Option<(&Competitor, &Competitor)> {
+    // golf fields aren't one side against another
+    if competition.competitors.len() != 2 {
+        return None;
+    }
+    let away = competition.competitors.iter().find(|c| c.home_away == "away");
+    let home = competition.competitors.iter().find(|c| c.home_away == "home");
+    match (away, home) {
+        (Some(away), Some(home)) => Some((away, home)),
+        _ => Some((competition.competitors.first()?, competition.competitors.get(1)?)),
+    }
+}
+
+fn detect_game_changes(old_events: &[GameEvent], new_events: &[GameEvent]) -> Vec<GameChange> {
+    let mut changes = Vec::new();
+
+    for new_event in new_events {
+        let Some(old_event) = old_events.iter().find(|event| event.id == new_event.id) else {
+            continue;
+        };
+
+        for new_comp in &new_event.competitions {
+            let Some(old_comp) = old_event.competitions.iter().find(|comp| comp.id == new_comp.id) else {
+                continue;
+            };
+            let (Some((old_away, old_home)), Some((away, home))) = (away_home(old_comp), away_home(new_comp)) else {
+                continue;
+            };
+
+            let change = |kind, scoring_team: Option<&str>| GameChange {
+                kind,
+                event_id: new_event.id.clone(),
+                scoring_team: scoring_team.map(str::to_string),
+                away_team: away.short_name().to_string(),
+                away_score: away.score.clone(),
+                home_team: home.short_name().to_string(),
+                home_score: home.score.clone(),
+                competition: new_comp.clone(),
+            };
+
+            let old_state = old_comp.status.status_type.state.as_str();
+            let new_state = new_comp.status.status_type.state.as_str();
+            if old_state == "pre" && new_state == "in" {
+                changes.push(change(ChangeKind::Start, None));
+            }
+
+            // both teams can score between polls, then there's no one team to credit
+            let scored = (away.score != old_away.score, home.score != old_home.score);
+            let scoring_team = match scored {
+                (true, false) => Some(away.short_name()),
+                (false, true) => Some(home.short_name()),
+                _ => None,
+            };
+            if scored.0 || scored.1 {
+                let old_leader = leader(old_away, old_home);
+                let new_leader = leader(away, home);
+                let kind = if old_leader.is_some() && new_leader.is_some() && old_leader != new_leader {
+                    ChangeKind::LeadChange
+                } else {
+                    ChangeKind::Score
+                };
+                changes.push(change(kind, scoring_team));
+            }
+
+            if old_state != "post" && new_state == "post" && new_comp.status.status_type.completed {
+                changes.push(change(ChangeKind::Final, None));
+            }
+        }
+    }
+
+    changes
+}
+
+// abbreviation of the team ahead, none when tied or not scored yet
+fn leader<'a>(away: &'a Competitor, home: &'a Competitor) -> Option<&'a str> {
+    let away_score = away.score.parse::<i64>().ok()?;
+    let home_score = home.score.parse::<i64>().ok()?;
+    match away_score.cmp(&home_score) {
+        Ordering::Greater => Some(away.short_name()),
+        Ordering::Less => Some(home.short_name()),
+        Ordering::Equal => None,
+    }
+}
+
+impl AppState {
+    fn record_game_changes(&mut self, changes: &[GameChange]) {
+        let now = Utc::now();
+        for change in changes {
+            // a hidden game only logs its start and finish, without the score
+            let hidden = self.hides_change(change);
+            if hidden && matches!(change.kind, ChangeKind::Score | ChangeKind::LeadChange) {
+                continue;
+            }
+            // the whole card flashes when both teams scored
+            if matches!(change.kind, ChangeKind::Score | ChangeKind::LeadChange) {
+                self.flashes.insert(change.event_id.clone(), Flash {
+                    team: change.scoring_team.clone().unwrap_or_default(),
+                    started: Instant::now(),
+                });
+            }
+
+            let text = format!("{} {}", self.format_clock_time(now), self.format_change(change));
+            self.event_log.insert(0, LogEntry { time: now, text });
+        }
+
+        self.event_log.truncate(MAX_LOG_ENTRIES);
+        self.flashes.retain(|_, flash| flash.started.elapsed() < FLASH_DURATION);
+    }
+
+    // i.e. "CLE 3-2 DET (B7)"
+    fn format_change(&self, change: &GameChange) -> String {
+        let status = match change.kind {
+            ChangeKind::Final => "FINAL".to_string(),
+            _ => self.format_live_status(&change.competition),
+        };
+        let (away_score, home_score) = if self.hides_change(change) {
+            (HIDDEN_SCORE, HIDDEN_SCORE)
+        } else {
+            (change.away_score.as_str(), change.home_score.as_str())
+        };
+        format!(
+            "{} {}-{} {} ({})",
+            change.away_team,
+            away_score,
+            home_score,
+            change.home_team,
+            status
+        )
+    }
+
+    fn active_flash(&self, event: &GameEvent) -> Option<&Flash> {
+        self.flashes.get(&event.id)
+            .filter(|flash| flash.started.elapsed() < FLASH_DURATION)
+    }
+
+    fn format_clock_time(&self, time: DateTime<Utc>) -> String {
+        match self.timezone {
+            TimeZoneSetting::Local => time.with_timezone(&Local).format("%H:%M").to_string(),
+            TimeZoneSetting::Named(tz) => time.with_timezone(&tz).format("%H:%M").to_string(),
+        }
+    }
+
+    fn toggle_log(&mut self) {
+        self.show_log = !self.show_log;
+        self.log_scroll = 0;
+    }
+
+    fn scroll_log_up(&mut self) {
+        self.log_scroll = self.log_scroll.saturating_sub(1);
+    }
+
+    fn scroll_log_down(&mut self) {
+        if self.log_scroll + 1 < self.event_log.len() {
+            self.log_scroll += 1;
+        }
+    }
+}
+
+// notifications
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum OscMode {
+    #[default]
+    Off,
+    #[serde(rename = "9")]
+    Osc9,
+    #[serde(rename = "777")]
+    Osc777,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+struct NotifyConfig {
+    bell: bool,
+    osc: OscMode,
+    command: Option<String>,
+    events: Vec<ChangeKind>,
+}
+
+impl Default for NotifyConfig {
+    fn default() -> Self {
+        Self {
+            bell: false,
+            osc: OscMode::Off,
+            command: None,
+            events: vec![ChangeKind::Score, ChangeKind::LeadChange, ChangeKind::Start, ChangeKind::Final],
+        }
+    }
+}
+
+impl NotifyConfig {
+    fn is_enabled(&self) -> bool {
+        self.bell || self.osc != OscMode::Off || self.command.is_some()
+    }
+
+    // when stdout is read by another program the bell and osc bytes would end up in its input,
+    // so only the command is left
+    fn without_terminal(&mut self) {
+        self.bell = false;
+        self.osc = OscMode::Off;
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Alert {
+    title: String,
+    body: String,
+}
+
+impl Alert {
+    fn for_change(change: &GameChange, body: String) -> Self {
+        let title = match (change.kind, &change.scoring_team) {
+            (ChangeKind::Score, Some(team)) => format!("{} scored", team),
+            (ChangeKind::LeadChange, Some(team)) => format!("{} takes the lead", team),
+            (ChangeKind::Score, None) => format!("{} @ {} both scored", change.away_team, change.home_team),
+            (ChangeKind::LeadChange, None) => format!("lead change in {} @ {}", change.away_team, change.home_team),
+            (ChangeKind::Start, _) => format!("{} @ {} started", change.away_team, change.home_team),
+            (ChangeKind::Final, _) => format!("{} @ {} final", change.away_team, change.home_team),
+        };
+        Self { title: format!("scrbrd: {}", title), body }
+    }
+}
+
+impl AppState {
+    // alerts only fire for watched games: favorites, or the -t team without any
+    fn is_watched(&self, event_id: &str) -> bool {
+        let Some(event) = self.events.iter().find(|event| event.id == event_id) else {
+            return false;
+        };
+        if self.favorites.is_empty() {
+            self.team_filter.as_ref().is_some_and(|team| event_matches_team(event, team))
+        } else {
+            self.is_favorite(event)
+        }
+    }
+
+    fn notify_game_changes(&self, changes: &[GameChange]) {
+        if !self.notify.is_enabled() {
+            return;
+        }
+
+        for change in changes {
+            let spoils = self.hides_change(change) && matches!(change.kind, ChangeKind::Score | ChangeKind::LeadChange);
+            if self.notify.events.contains(&change.kind) && self.is_watched(&change.event_id) && !spoils {
+                send_alert(&self.notify, &Alert::for_change(change, self.format_change(change)));
+            }
+        }
+    }
+}
+
+fn send_alert(notify: &NotifyConfig, alert: &Alert) {
+    let mut sequences = String::new();
+    if notify.bell {
+        sequences.push('\x07');
+    }
+    match notify.osc {
+        OscMode::Off => {}
+        OscMode::Osc9 => sequences.push_str(&wrap_for_tmux(&format!("\x1b]9;{}: {}\x07", alert.title, alert.body))),
+        OscMode::Osc777 => sequences.push_str(&wrap_for_tmux(&format!("\x1b]777;notify;{};{}\x07", alert.title, alert.body))),
+    }
+    if !sequences.is_empty() {
+        let mut stdout = io::stdout();
+        let _ = stdout.write_all(sequences.as_bytes()).and_then(|_| stdout.flush());
+    }
+
+    if let Some(ref command) = notify.command {
+        run_alert_command(command, alert);
+    }
+}
+
+// tmux only forwards escape sequences to the outer terminal inside a passthrough
+fn wrap_for_tmux(sequence: &str) -> String {
+    if std::env::var_os("TMUX").is_some() {
+        format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b"))
+    } else {
+        sequence.to_string()
+    }
+}
+
+// the alert is passed through the environment so it never gets shell-interpolated
+fn run_alert_command(command: &str, alert: &Alert) {
+    let child = tokio::process::Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .env("SCRBRD_TITLE", &alert.title)
+        .env("SCRBRD_BODY", &alert.body)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn();
+
+    if let Ok(mut child) = child {
+        tokio::spawn(async move {
+            let _ = child.wait().await;
+        });
+    }
+}
+
+// alert rules
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum RuleAction {
+    Highlight,
+    Notify,
+    Command,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct AlertRule {
+    name: String,
+    league: Option<String>,
+    team: Option<String>,
+    // pre, in or post (live, upcoming and final work too)
+    state: Option<String>,
+    period_min: Option<u32>,
+    period_max: Option<u32>,
+    // remaining game clock, i.e. "2:00"
+    clock_max: Option<String>,
+    margin_min: Option<u32>,
+    margin_max: Option<u32>,
+    // baseball: a team still without a hit after this many innings
+    no_hitter_through: Option<u32>,
+    actions: Vec<RuleAction>,
+    command: Option<String>,
+}
+
+impl AlertRule {
+    fn matches(&self, league: &str, event: &GameEvent, competition: &Competition) -> bool {
+        let status = &competition.status;
+
+        if self.league.as_ref().is_some_and(|l| !same_league(l, league)) {
+            return false;
+        }
+        if self.team.as_ref().is_some_and(|team| !event_matches_team(event, team)) {
+            return false;
+        }
+        if let Some(ref state) = self.state {
+            let state = state.to_lowercase();
+            // espn's own "pre", "in" and "post" work too
+            let wanted = match state.as_str() {
+                "live" => "in",
+                "upcoming" => "pre",
+                "final" => "post",
+                other => other,
+            };
+            if wanted != status.status_type.state {
+                return false;
+            }
+        }
+        if self.period_min.is_some_and(|min| status.period < min) ||
+           self.period_max.is_some_and(|max| status.period > max) {
+            return false;
+        }
+        if let Some(ref clock_max) = self.clock_max {
+            match (parse_clock_seconds(&status.display_clock), parse_clock_seconds(clock_max)) {
+                (Some(remaining), Some(max)) if remaining <= max => {}
+                _ => return false,
+            }
+        }
+        if self.margin_min.is_some() || self.margin_max.is_some() {
+            let Some((away, home)) = away_home(competition) else {
+                return false;
+            };
+            let (Ok(away_score), Ok(home_score)) = (away.score.parse::<i64>(), home.score.parse::<i64>()) else {
+                return false;
+            };
+            let margin = away_score.abs_diff(home_score) as u32;
+            if self.margin_min.is_some_and(|min| margin < min) ||
+               self.margin_max.is_some_and(|max| margin > max) {
+                return false;
+            }
+        }
+        if let Some(innings) = self.no_hitter_through {
+            let innings_done = if format_baseball_status(status).starts_with('E') {
+                status.period
+            } else {
+                status.period.saturating_sub(1)
+            };
+            let hitless = competition.competitors.iter().any(|c| c.hits == Some(0));
+            if status.status_type.state != "in" || innings_done < innings || !hitless {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+// "4:32" or "45.2" to seconds
+fn parse_clock_seconds(clock: &str) -> Option<f64> {
+    match clock.split_once(':') {
+        Some((minutes, seconds)) => Some(minutes.trim().parse::<f64>().ok()? * 60.0 + seconds.trim().parse::<f64>().ok()?),
+        None => clock.trim().parse::<f64>().ok(),
+    }
+}
+
+impl AppState {
+    // actions only fire when a rule starts matching a game, not on every refresh.
+    // every rule fires on its own, so a game can set off more than one
+    fn evaluate_rules(&mut self) {
+        let mut matches = HashSet::new();
+        // margins and no-hitters give the score away
+        for event in self.events.iter().filter(|event| !self.hides_score(event)) {
+            for competition in &event.competitions {
+                for (index, rule) in self.rules.iter().enumerate() {
+                    if rule.matches(&self.selected_league, event, competition) {
+                        matches.insert((event.id.clone(), index));
+                    }
+                }
+            }
+        }
+
+        // in rule order, so alerts for the same game come out the same way every time
+        let mut started: Vec<&(String, usize)> = matches.difference(&self.rule_matches).collect();
+        started.sort_by_key(|(_, index)| *index);
+        for (event_id, index) in started {
+            let rule = &self.rules[*index];
+            let Some(event) = self.events.iter().find(|event| &event.id == event_id) else {
+                continue;
+            };
+            let alert = Alert {
+                title: format!("scrbrd: {}", rule.name),
+                body: self.format_event_summary(event),
+            };
+            if rule.actions.contains(&RuleAction::Notify) {
+                send_alert(&self.notify, &alert);
+            }
+            if rule.actions.contains(&RuleAction::Command)
+                && let Some(ref command) = rule.command {
+                run_alert_command(command, &alert);
+            }
+        }
+
+        self.rule_matches = matches;
+    }
+
+    fn highlight_rule(&self, event: &GameEvent) -> Option<&AlertRule> {
+        self.rules.iter().enumerate()
+            .find(|(index, rule)| rule.actions.contains(&RuleAction::Highlight)
+                && self.rule_matches.contains(&(event.id.clone(), *index)))
+            .map(|(_, rule)| rule)
+    }
+
+    // i.e. "CLE 3-2 DET (B7)"
+    fn format_event_summary(&self, event: &GameEvent) -> String {
+        event.competitions.first()
+            .and_then(|comp| {
+                let (away, home) = away_home(comp)?;
+                let status = if comp.status.status_type.state == "in" {
+                    self.format_live_status(comp)
+                } else {
+                    self.format_status(comp)
+                };
+                Some(format!(
+                    "{} {}-{} {} ({})",
+                    away.short_name(),
+                    self.shown_score(event, away),
+                    self.shown_score(event, home),
+                    home.short_name(),
+                    status
+                ))
+            })
+            .unwrap_or_else(|| event.short_name.clone())
+    }
+}
+
+// leagues
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Sport {
+    Baseball,
+    Football,
+    Basketball,
+    // two halves instead of four quarters
+    CollegeBasketball,
+    Hockey,
+    Soccer,
+    Golf,
+    Racing,
+    Tennis,
+    Mma,
+}
+
+// college scoreboards only list top 25 games unless they're asked for a group
+#[derive(Debug)]
+struct CollegeGroups {
+    default: &'static str,
+    conferences: &'static [(&'static str, &'static str)],
+}
+
+#[derive(Debug)]
+struct LeagueInfo {
+    slug: &'static str,
+    name: &'static str,
+    aliases: &'static [&'static str],
+    sport: Sport,
+    path: &'static str,
+    groups: Option<&'static CollegeGroups>,
+}
+
+const fn league(slug: &'static str, name: &'static str, aliases: &'static [&'static str], sport: Sport, path: &'static str) -> LeagueInfo {
+    LeagueInfo { slug, name, aliases, sport, path, groups: None }
+}
+
+// every league scrbrd knows, in the order --help lists them
+const LEAGUES: &[LeagueInfo] = &[
+    league("mlb", "MLB", &[], Sport::Baseball, "baseball/mlb"),
+    league("nba", "NBA", &[], Sport::Basketball, "basketball/nba"),
+    league("wnba", "WNBA", &[], Sport::Basketball, "basketball/wnba"),
+    league("nfl", "NFL", &[], Sport::Football, "football/nfl"),
+    league("nhl", "NHL", &[], Sport::Hockey, "hockey/nhl"),
+    LeagueInfo {
+        groups: Some(&COLLEGE_FOOTBALL_GROUPS),
+        ..league("college-football", "College Football", &["cfb", "ncaaf"], Sport::Football, "football/college-football")
+    },
+    LeagueInfo {
+        groups: Some(&COLLEGE_BASKETBALL_GROUPS),
+        ..league("mens-college-basketball", "Men's College Basketball", &["cbb", "ncaam"], Sport::CollegeBasketball, "basketball/mens-college-basketball")
+    },
+    LeagueInfo {
+        groups: Some(&COLLEGE_BASKETBALL_GROUPS),
+        ..league("womens-college-basketball", "Women's College Basketball", &["wcbb", "ncaaw"], Sport::Basketball, "basketball/womens-college-basketball")
+    },
+    league("mls", "MLS", &[], Sport::Soccer, "soccer/usa.1"),
+    league("nwsl", "NWSL", &[], Sport::Soccer, "soccer/usa.nwsl"),
+    league("premier", "Premier League", &["epl", "prem", "premier-league"], Sport::Soccer, "soccer/eng.1"),
+    league("championship", "EFL Championship", &["efl", "eng2"], Sport::Soccer, "soccer/eng.2"),
+    league("fa-cup", "FA Cup", &["facup"], Sport::Soccer, "soccer/eng.fa"),
+    league("laliga", "LaLiga", &["la-liga", "esp"], Sport::Soccer, "soccer/esp.1"),
+    league("seriea", "Serie A", &["serie-a", "ita"], Sport::Soccer, "soccer/ita.1"),
+    league("bundesliga", "Bundesliga", &["ger", "buli"], Sport::Soccer, "soccer/ger.1"),
+    league("ligue1", "Ligue 1", &["ligue-1", "fra"], Sport::Soccer, "soccer/fra.1"),
+    league("ligamx", "Liga MX", &["liga-mx", "mex"], Sport::Soccer, "soccer/mex.1"),
+    league("ucl", "UEFA Champions League", &["champions-league", "champions"], Sport::Soccer, "soccer/uefa.champions"),
+    league("uel", "UEFA Europa League", &["europa-league", "europa"], Sport::Soccer, "soccer/uefa.europa"),
+    league("uecl", "UEFA Conference League", &["conference-league"], Sport::Soccer, "soccer/uefa.europa.conf"),
+    league("world-cup", "FIFA World Cup", &["worldcup", "wc"], Sport::Soccer, "soccer/fifa.world"),
+    league("womens-world-cup", "FIFA Women's World Cup", &["wwc"], Sport::Soccer, "soccer/fifa.wwc"),
+    league("euro", "UEFA European Championship", &["euros"], Sport::Soccer, "soccer/uefa.euro"),
+    league("nations-league", "UEFA Nations League", &["unl"], Sport::Soccer, "soccer/uefa.nations"),
+    league("copa-america", "Copa América", &["copa"], Sport::Soccer, "soccer/conmebol.america"),
+    league("gold-cup", "CONCACAF Gold Cup", &["goldcup"], Sport::Soccer, "soccer/concacaf.gold"),
+    league("friendlies", "International Friendlies", &["friendly"], Sport::Soccer, "soccer/fifa.friendly"),
+    league("pga", "PGA Tour", &["pga-tour"], Sport::Golf, "golf/pga"),
+    league("lpga", "LPGA Tour", &["lpga-tour"], Sport::Golf, "golf/lpga"),
+    league("f1", "Formula 1", &["formula1", "formula-1"], Sport::Racing, "racing/f1"),
+    league("nascar", "NASCAR Cup Series", &["nascar-cup"], Sport::Racing, "racing/nascar-premier"),
+    league("indycar", "IndyCar", &["irl"], Sport::Racing, "racing/irl"),
+    league("atp", "ATP Tour", &["atp-tour"], Sport::Tennis, "tennis/atp"),
+    league("wta", "WTA Tour", &["wta-tour"], Sport::Tennis, "tennis/wta"),
+    league("ufc", "UFC", &[], Sport::Mma, "mma/ufc"),
+];
+
+fn find_league(league: &str) -> Result<&'static LeagueInfo, Box<dyn Error>> {
+    let league = league.trim().to_lowercase();
+    LEAGUES.iter()
+        .find(|info| info.slug == league || info.aliases.contains(&league.as_str()))
+        .ok_or_else(|| format!("unsupported league: {} (see --help)", league).into())
+}
+
+// aliases count, so a "prem" rule matches the premier league
+fn same_league(a: &str, b: &str) -> bool {
+    match (find_league(a), find_league(b)) {
+        (Ok(a), Ok(b)) => a.slug == b.slug,
+        _ => a.eq_ignore_ascii_case(b),
+    }
+}
+
+fn league_name(league: &str) -> String {
+    find_league(league).map_or_else(|_| league.to_string(), |info| info.name.to_string()).to_lowercase()
+}
+
+fn league_help() -> String {
+    let slugs: Vec<&str> = LEAGUES.iter().map(|info| info.slug).collect();
+    format!("supported leagues: {} (required), comma separated for tabs", slugs.join(", "))
+}
+
+// college conferences
+
+const COLLEGE_FOOTBALL_GROUPS: CollegeGroups = CollegeGroups {
+    // every fbs game
+    default: "80",
+    conferences: &[
+        ("acc", "1"),
+        ("american", "151"),
+        ("aac", "151"),
+        ("big12", "4"),
+        ("bigten", "5"),
+        ("big10", "5"),
+        ("b1g", "5"),
+        ("cusa", "12"),
+        ("independents", "18"),
+        ("mac", "15"),
+        ("mountainwest", "17"),
+        ("mwc", "17"),
+        ("pac12", "9"),
+        ("sec", "8"),
+        ("sunbelt", "37"),
+        ("fbs", "80"),
+        ("fcs", "81"),
+    ],
+};
+
+const COLLEGE_BASKETBALL_GROUPS: CollegeGroups = CollegeGroups {
+    // every division i game
+    default: "50",
+    conferences: &[
+        ("acc", "2"),
+        ("american", "62"),
+        ("aac", "62"),
+        ("a10", "3"),
+        ("atlantic10", "3"),
+        ("bigeast", "4"),
+        ("big12", "8"),
+        ("bigten", "7"),
+        ("big10", "7"),
+        ("b1g", "7"),
+        ("ivy", "12"),
+        ("mountainwest", "44"),
+        ("mwc", "44"),
+        ("missourivalley", "18"),
+        ("mvc", "18"),
+        ("pac12", "21"),
+        ("sec", "23"),
+        ("wcc", "29"),
+        ("d1", "50"),
+    ],
+};
+
+fn is_college_league(league: &str) -> bool {
+    find_league(league).is_ok_and(|info| info.groups.is_some())
+}
+
+// the groups query for a college league, none for the pros
+fn college_group(league: &str, conference: Option<&str>) -> Result<Option<&'static str>, Box<dyn Error>> {
+    let Some(groups) = find_league(league)?.groups else {
+        return Ok(None);
+    };
+    let Some(conference) = conference else {
+        return Ok(Some(groups.default));
+    };
+
+    // "big ten", "Big-Ten" and "bigten" are all the same conference
+    let key: String = conference.to_lowercase().chars().filter(|c| c.is_ascii_alphanumeric()).collect();
+    groups.conferences.iter()
+        .find(|(name, _)| *name == key)
+        .map(|(_, group)| Some(*group))
+        .ok_or_else(|| format!("unknown {} conference: {}", league.to_lowercase(), conference).into())
+}
+
+// spoilers
+
+// hides scores for games watched on delay, every game when no teams or leagues are listed
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct SpoilerConfig {
+    enabled: bool,
+    teams: Vec<String>,
+    leagues: Vec<String>,
+}
+
+const HIDDEN_SCORE: &str = "?";
+
+impl AppState {
+    fn hides_score(&self, event: &GameEvent) -> bool {
+        let spoilers = &self.spoilers;
+        if !spoilers.enabled || self.revealed.contains(&event.id) {
+            return false;
+        }
+        if spoilers.teams.is_empty() && spoilers.leagues.is_empty() {
+            return true;
+        }
+        spoilers.leagues.iter().any(|league| same_league(league, &self.selected_league)) ||
+        spoilers.teams.iter().any(|team| event_matches_team(event, team))
+    }
+
+    // for the status formatting, which only gets the competition
+    fn hides_competition(&self, competition: &Competition) -> bool {
+        self.events.iter()
+            .find(|event| event.competitions.iter().any(|other| other.id == competition.id))
+            .is_some_and(|event| self.hides_score(event))
+    }
+
+    // espn's own wording says "FT-Pens", "AET" or how a fight ended, so a hidden game gets ours
+    fn shown_detail(&self, event: &GameEvent, competition: &Competition, detail: &str) -> String {
+        if self.hides_score(event) { self.format_status_brief(competition) } else { detail.to_string() }
+    }
+
+    fn shown_margin(&self, event: &GameEvent) -> Option<u32> {
+        if self.hides_score(event) { None } else { score_margin(event) }
+    }
+
+    fn hides_change(&self, change: &GameChange) -> bool {
+        self.events.iter()
+            .find(|event| event.id == change.event_id)
+            .is_some_and(|event| self.hides_score(event))
+    }
+
+    fn shown_score<'a>(&self, event: &GameEvent, competitor: &'a Competitor) -> &'a str {
+        if self.hides_score(event) {
+            HIDDEN_SCORE
+        } else {
+            &competitor.score
+        }
+    }
+
+    // records move after a final, so they go with the score. a playoff series shows where it stands instead
+    fn shown_records(&self, event: &GameEvent, competition: &Competition) -> Option<String> {
+        let (away, home) = away_home(competition)?;
+        if self.hides_score(event) {
+            None
+        } else {
+            series_summary(competition).or_else(|| format_records(away, home))
+        }
+    }
+
+    // the game number says how far the series has gone, so it goes with the score too
+    fn shown_series_heading(&self, event: &GameEvent, competition: &Competition) -> Option<(String, bool)> {
+        if self.hides_score(event) {
+            let series = playoff_series(competition).filter(|series| !series.title.is_empty())?;
+            Some((series.title.clone(), false))
+        } else {
+            series_heading(competition)
+        }
+    }
+
+    // "G5 · CLE leads 3-2", short enough for the compact table
+    fn format_series_cell(&self, event: &GameEvent, competition: &Competition) -> Option<String> {
+        let records = self.shown_records(event, competition)?;
+        match series_game(competition) {
+            Some((game, _)) => Some(format!("G{} · {}", game, records)),
+            None => Some(records),
+        }
+    }
+
+    // "East 1st Round · Game 5 · CLE leads 3-2", for the outputs without a card title
+    fn format_series_line(&self, event: &GameEvent, competition: &Competition) -> Option<String> {
+        let (heading, _) = self.shown_series_heading(event, competition)?;
+        Some(match self.shown_records(event, competition) {
+            Some(summary) => format!("{} · {}", heading, summary),
+            None => heading,
+        })
+    }
+
+    // with nothing selected the first press only picks a game, so nothing is revealed by accident
+    fn toggle_reveal(&mut self) {
+        let Some(event_id) = self.selected.clone() else {
+            self.move_selection(0);
+            return;
+        };
+        if !self.revealed.remove(&event_id) {
+            self.revealed.insert(event_id);
+        }
+    }
+}
+
+// score block formatting
+
+impl AppState {
+    fn format_game_widget(&self, event: &GameEvent) -> Paragraph<'_> {
+        let mut content = Vec::new();
+        let flash = self.active_flash(event);
+
+        for competition in &event.competitions {
+            if let Some((away, home)) = away_home(competition) {
+                if self.is_tennis() {
+                    content.extend(self.format_tennis_lines(event, away, home));
+                } else {
+                    // score line, with the team that just scored highlighted
+                    let score_style = self.theme.score;
+                    let score = if self.is_fight() {
+                        " vs ".to_string()
+                    } else {
+                        format!(" {} - {} ", self.shown_score(event, away), self.shown_score(event, home))
+                    };
+                    content.push(Line::from(vec![
+                        Span::styled(format_rank(away), self.theme.dim),
+                        Span::styled(away.short_name().to_string(), self.winner_style(event, away, self.team_style(&away.team, flash))),
+                        Span::styled(score, score_style),
+                        Span::styled(format_rank(home), self.theme.dim),
+                        Span::styled(home.short_name().to_string(), self.winner_style(event, home, self.team_style(&home.team, flash))),
+                    ]).alignment(Alignment::Center));
+                }
+
+
+                // status line
+                let status_line = self.format_status(competition);
+                if !status_line.is_empty() {
+                    let status_style = get_status_style(&self.theme, &status_line);
+                    content.push(Line::from(vec![
+                        Span::styled(status_line, status_style)
+                    ]).alignment(Alignment::Center));
+                    content.push(Line::from(""));
+                }
+
+                // records line
+                add_records_line(&mut content, &self.theme, self.shown_records(event, competition));
+            }
+        }
+
+        // blink the border for the first few seconds after a score
+        let highlight = self.highlight_rule(event);
+        let border_style = match (flash, highlight) {
+            (Some(flash), _) if flash.started.elapsed().as_millis() / 500 % 2 == 0 => self.theme.flash,
+            (Some(_), _) => self.theme.accent,
+            (None, Some(_)) => self.theme.highlight,
+            (None, None) => self.card_border_style(event),
+        };
+
+        let mut block = Block::default().borders(Borders::ALL).border_style(border_style);
+        if self.is_selected(event) {
+            block = block.border_type(BorderType::Thick);
+        }
+        if let Some(rule) = highlight {
+            block = block.title(format!(" {} ", rule.name)).title_alignment(Alignment::Center);
+        } else if let Some((heading, deciding)) = event.competitions.first().and_then(|competition| self.shown_series_heading(event, competition)) {
+            // a game 7 shouldn't look like any other game
+            let style = if deciding { self.theme.live.add_modifier(Modifier::BOLD) } else { self.theme.dim };
+            block = block.title(Span::styled(format!(" {} ", heading), style)).title_alignment(Alignment::Center);
+        } else if let Some(heading) = event.competitions.first().and_then(|competition| match_heading(event, competition)) {
+            block = block.title(Span::styled(format!(" {} ", heading), self.theme.dim)).title_alignment(Alignment::Center);
+        }
+
+        Paragraph::new(content)
+            .block(block)
+            .alignment(Alignment::Center)
+    }
+
+    fn format_game_row(&self, event: &GameEvent) -> Vec<Row<'static>> {
+        let flash = self.active_flash(event);
+        let mut row_style = match (flash, self.highlight_rule(event)) {
+            (Some(_), _) => self.theme.accent,
+            (None, Some(_)) => self.theme.highlight,
+            (None, None) => Style::default(),
+        };
+        if self.is_selected(event) {
+            row_style = row_style.add_modifier(Modifier::REVERSED);
+        }
+
+        event.competitions.iter()
+            .filter_map(|competition| {
+                let (away, home) = away_home(competition)?;
+                let status_line = plain_status(&self.format_status(competition));
+                let broadcast = competition.broadcasts.iter()
+                    .flat_map(|broadcast| broadcast.names.iter())
+                    .next()
+                    .cloned()
+                    .unwrap_or_default();
+                let score = if self.is_fight() {
+                    "vs".to_string()
+                } else {
+                    format!("{} - {}", self.shown_score(event, away), self.shown_score(event, home))
+                };
+
+                Some(Row::new(vec![
+                    Cell::from(format!("{}{}", format_rank(away), away.short_name())).style(self.winner_style(event, away, self.team_style(&away.team, flash))),
+                    Cell::from(score).style(Style::default().add_modifier(Modifier::BOLD)),
+                    Cell::from(format!("{}{}", format_rank(home), home.short_name())).style(self.winner_style(event, home, self.team_style(&home.team, flash))),
+                    Cell::from(status_line).style(get_group_style(&self.theme, GameGroup::of(event))),
+                    Cell::from(self.format_series_cell(event, competition).unwrap_or_default()).style(self.theme.dim),
+                    Cell::from(broadcast).style(self.theme.dim),
+                ]).style(row_style))
+            })
+            .collect()
+    }
+
+    fn is_tennis(&self) -> bool {
+        matches!(find_league(&self.selected_league).map(|league| league.sport), Ok(Sport::Tennis))
+    }
+
+    fn is_fight(&self) -> bool {
+        matches!(find_league(&self.selected_league).map(|league| league.sport), Ok(Sport::Mma))
+    }
+
+    // fights have no score to tell who won, so the winner is underlined
+    fn winner_style(&self, event: &GameEvent, competitor: &Competitor, style: Style) -> Style {
+        if self.is_fight() && competitor.winner && !self.hides_score(event) {
+            style.add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+        } else {
+            style
+        }
+    }
+
+    fn team_style(&self, team: &Team, flash: Option<&Flash>) -> Style {
+        match flash {
+            Some(flash) if flash.team == team.abbreviation => self.theme.flash,
+            _ => match self.theme.team_color(team) {
+                Some(color) => self.theme.score.fg(color),
+                None => self.theme.score,
+            },
+        }
+    }
+
+    // the favorite's color when one is playing, otherwise the home team's
+    fn card_border_style(&self, event: &GameEvent) -> Style {
+        let Some((away, home)) = event.competitions.first().and_then(away_home) else {
+            return self.theme.border;
+        };
+        let is_favorite = |team: &Team| self.favorites.iter().any(|favorite| team_matches(team, favorite));
+        let team = if is_favorite(&away.team) && !is_favorite(&home.team) { away } else { home };
+        match self.theme.team_color(&team.team) {
+            Some(color) => self.theme.border.fg(color),
+            None => self.theme.border,
+        }
+    }
+
+    fn format_status(&self, competition: &Competition) -> String {
+        let status = &competition.status;
+        match status.status_type.state.as_str() {
+            "pre" => self.format_pregame_status(competition),
+            "in" => format!("🔴 LIVE | {}", self.format_live_status(competition)),
+            "post" => {
+                if status.status_type.completed {
+                    match find_league(&self.selected_league).map(|league| league.sport) {
+                        Ok(Sport::Soccer) => format_soccer_final(competition, self.hides_competition(competition)),
+                        Ok(Sport::Mma) if !self.hides_competition(competition) => format_fight_result(&competition.status),
+                        _ => "FINAL".to_string(),
+                    }
+                } else {
+                    status.status_type.short_detail.clone()
+                }
+            },
+            _ => status.status_type.short_detail.clone(),
+        }
+    }
+
+    fn format_pregame_status(&self, competition: &Competition) -> String {
+        let status = &competition.status;
+        // postponed, delayed, etc. keep espn's wording
+        if status.status_type.name != "STATUS_SCHEDULED" {
+            return status.status_type.short_detail.clone();
+        }
+
+        let Some(start) = parse_event_date(&competition.date) else {
+            return status.status_type.short_detail.clone();
+        };
+
+        let kickoff = self.format_kickoff(start);
+        let until = start - Utc::now();
+        if until <= chrono::Duration::zero() {
+            format!("{} | starting soon", kickoff)
+        } else if until <= chrono::Duration::hours(COUNTDOWN_HOURS) {
+            format!("{} | starts in {}", kickoff, format_countdown(until))
+        } else {
+            kickoff
+        }
+    }
+
+    fn format_kickoff(&self, start: DateTime<Utc>) -> String {
+        match self.timezone {
+            TimeZoneSetting::Local => format_local_kickoff(&Local, start, "%-I:%M %p"),
+            TimeZoneSetting::Named(tz) => format_local_kickoff(&tz, start, "%-I:%M %p %Z"),
+        }
+    }
+
+    // "7:05p", for status bars
+    fn format_short_kickoff(&self, start: DateTime<Utc>) -> String {
+        let kickoff = match self.timezone {
+            TimeZoneSetting::Local => format_local_kickoff(&Local, start, "%-I:%M%P"),
+            TimeZoneSetting::Named(tz) => format_local_kickoff(&tz, start, "%-I:%M%P"),
+        };
+        kickoff.trim_end_matches('m').to_string()
+    }
+
+    fn format_live_status(&self, competition: &Competition) -> String {
+        let status = &competition.status;
+        match find_league(&self.selected_league).map(|league| league.sport) {
+            Ok(Sport::Football) => format_football_status(status),
+            Ok(Sport::Basketball) => format_basketball_status(status),
+            Ok(Sport::CollegeBasketball) => format_college_basketball_status(status),
+            Ok(Sport::Hockey) => format_hockey_status(status),
+            Ok(Sport::Baseball) => format_baseball_status(status),
+            Ok(Sport::Soccer) => format_soccer_status(competition, self.hides_competition(competition)),
+            Ok(Sport::Golf) => format!("Round {}", status.period.max(1)),
+            Ok(Sport::Racing) => status.status_type.short_detail.clone(),
+            Ok(Sport::Tennis) => format_tennis_status(competition, self.hides_competition(competition)),
+            Ok(Sport::Mma) => format_fight_clock(&competition.status),
+            Err(_) => format!("{} - {}", status.period, status.display_clock),
+        }
+    }
+}
+
+// games starting within this many hours get a countdown
+const COUNTDOWN_HOURS: i64 = 3;
+
+fn parse_event_date(date: &str) -> Option<DateTime<Utc>> {
+    // espn usually omits seconds (2025-06-01T17:10Z), which rfc3339 rejects
+    DateTime::parse_from_rfc3339(date)
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(date, "%Y-%m-%dT%H:%MZ")
+                .ok()
+                .map(|naive| naive.and_utc())
+        })
+}
+
+fn format_local_kickoff<Z: TimeZone>(tz: &Z, start: DateTime<Utc>, time_format: &str) -> String
+where
+    Z::Offset: std::fmt::Display,
+{
+    let local_start = start.with_timezone(tz);
+    let local_now = Utc::now().with_timezone(tz);
+    if local_start.date_naive() == local_now.date_naive() {
+        local_start.format(time_format).to_string()
+    } else {
+        local_start.format(&format!("%a {}", time_format)).to_string()
+    }
+}
+
+fn format_countdown(until: chrono::Duration) -> String {
+    let total_minutes = until.num_minutes().max(1);
+    let hours = total_minutes / 60;
+    let minutes = total_minutes % 60;
+    if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+fn add_records_line(content: &mut Vec<Line>, theme: &Theme, records: Option<String>) {
+    if let Some(record_line) = records {
+        content.push(Line::from(vec![
+            Span::styled(record_line, theme.dim)
+        ]).alignment(Alignment::Center));
+    }
+}
+
+// "#5 " before a ranked team, "(3) " before a seeded player
+fn format_rank(competitor: &Competitor) -> String {
+    match (competitor.rank(), competitor.seed()) {
+        (Some(rank), _) => format!("#{} ", rank),
+        (None, Some(seed)) => format!("({}) ", seed),
+        (None, None) => String::new(),
+    }
+}
+
+fn format_records(away: &Competitor, home: &Competitor) -> Option<String> {
+    let away_record = away.records.first()
+        .map(|r| r.summary.clone())
+        .unwrap_or_default();
+    let home_record = home.records.first()
+        .map(|r| r.summary.clone())
+        .unwrap_or_default();
+
+    if away_record.is_empty() && home_record.is_empty() {
+        None
+    } else {
+        Some(format!("({}) vs ({})", away_record, home_record))
+    }
+}
+
+fn get_status_style(theme: &Theme, status: &str) -> Style {
+    if status.contains("LIVE") {
+        theme.live
+    } else if status.contains("FINAL") {
+        theme.finished
+    } else {
+        theme.upcoming
+    }
+}
+
+// sport-specific formatting
+
+fn format_football_status(status: &Status) -> String {
+    match status.period {
+        1..=4 => format!("Q{}", status.period),
+        5 => "OT".to_string(),
+        _ => format!("Q{}", status.period),
+    }
+}
+
+fn format_basketball_status(status: &Status) -> String {
+    match status.period {
+        1..=4 => format!("Q{}", status.period),
+        5.. => "OT".to_string(),
+        _ => format!("Q{}", status.period),
+    }
+}
+
+// men's college games are two halves
+fn format_college_basketball_status(status: &Status) -> String {
+    match status.period {
+        3 => "OT".to_string(),
+        period @ 4.. => format!("{}OT", period - 2),
+        period => format!("H{}", period.max(1)),
+    }
+}
+
+fn format_hockey_status(status: &Status) -> String {
     match status.period {
         1..=3 => format!("P{}", status.period),
         4.. => "OT".to_string(),
@@ -343,292 +2590,2764 @@ fn format_hockey_status(status: &Status) -> String {
     }
 }
 
-fn format_baseball_status(status: &Status) -> String {
-    // try to determine inning half from short_detail or detail
-    if status.status_type.short_detail.to_lowercase().contains("bot") ||
-       status.status_type.detail.to_lowercase().contains("bot") ||
-       status.status_type.short_detail.to_lowercase().contains("bottom") ||
-       status.status_type.detail.to_lowercase().contains("bottom") {
-        return format!("B{}", status.period);
-    } else if status.status_type.short_detail.to_lowercase().contains("top") ||
-              status.status_type.detail.to_lowercase().contains("top") {
-        return format!("T{}", status.period);
-    } else if status.status_type.short_detail.to_lowercase().contains("mid") ||
-              status.status_type.detail.to_lowercase().contains("mid") ||
-              status.status_type.short_detail.to_lowercase().contains("middle") ||
-              status.status_type.detail.to_lowercase().contains("middle") {
-        return format!("M{}", status.period);
-    } else if status.status_type.short_detail.to_lowercase().contains("end") ||
-              status.status_type.detail.to_lowercase().contains("end") {
-        return format!("E{}", status.period);
+fn format_baseball_status(status: &Status) -> String {
+    // try to determine inning half from short_detail or detail
+    if status.status_type.short_detail.to_lowercase().contains("bot") ||
+       status.status_type.detail.to_lowercase().contains("bot") ||
+       status.status_type.short_detail.to_lowercase().contains("bottom") ||
+       status.status_type.detail.to_lowercase().contains("bottom") {
+        format!("B{}", status.period)
+    } else if status.status_type.short_detail.to_lowercase().contains("top") ||
+              status.status_type.detail.to_lowercase().contains("top") {
+        format!("T{}", status.period)
+    } else if status.status_type.short_detail.to_lowercase().contains("mid") ||
+              status.status_type.detail.to_lowercase().contains("mid") ||
+              status.status_type.short_detail.to_lowercase().contains("middle") ||
+              status.status_type.detail.to_lowercase().contains("middle") {
+        format!("M{}", status.period)
+    } else if status.status_type.short_detail.to_lowercase().contains("end") ||
+              status.status_type.detail.to_lowercase().contains("end") {
+        format!("E{}", status.period)
+    } else {
+        // fallback: just return the inning number
+        format!("{}", status.period)
+    }
+}
+
+// "Set 3", with the game score while it's being played
+fn format_tennis_status(competition: &Competition, hidden: bool) -> String {
+    let set = format!("Set {}", competition.status.period.max(1));
+    match away_home(competition).and_then(|(away, home)| away.points.as_ref().zip(home.points.as_ref())).filter(|_| !hidden) {
+        Some((away, home)) => format!("{} · {}-{}", set, away, home),
+        None => set,
+    }
+}
+
+// extra time and a shootout only happen after a draw, so a hidden game past 90 minutes just says so
+fn format_soccer_status(competition: &Competition, hidden: bool) -> String {
+    let status = &competition.status;
+    let name = status.status_type.name.as_str();
+    if hidden && (status.period > 2 || name.contains("SHOOTOUT")) {
+        return "90'+".to_string();
+    }
+    if name.contains("HALFTIME") {
+        return if status.period <= 2 { "HT".to_string() } else { "ET HT".to_string() };
+    }
+    if name.contains("SHOOTOUT") || status.period >= 5 {
+        return match shootout_score(competition) {
+            Some(score) => format!("PENS {}", score),
+            None => "PENS".to_string(),
+        };
+    }
+    match status.period {
+        0 | 1 => format!("{} 1H", format_soccer_minute(status)),
+        2 => format!("{} 2H", format_soccer_minute(status)),
+        period => format!("{} ET{}", format_soccer_minute(status), period.saturating_sub(2)),
+    }
+}
+
+// "67'", or "90+3'" once the clock runs past the end of the half
+fn format_soccer_minute(status: &Status) -> String {
+    let clock = status.display_clock.replace('\'', "");
+    if clock.contains('+') {
+        return format!("{}'", clock.trim());
+    }
+
+    let half_end = match status.period {
+        1 => 45,
+        2 => 90,
+        3 => 105,
+        _ => 120,
+    };
+    let minute = if status.clock > 0.0 {
+        (status.clock / 60.0).ceil() as u32
+    } else {
+        clock.trim().parse().unwrap_or(0)
+    };
+    if minute > half_end {
+        format!("{}+{}'", half_end, minute - half_end)
+    } else {
+        format!("{}'", minute)
+    }
+}
+
+// going to extra time or penalties gives away a draw, so a hidden game is only FINAL
+fn format_soccer_final(competition: &Competition, hidden: bool) -> String {
+    if hidden {
+        "FINAL".to_string()
+    } else if let Some(score) = shootout_score(competition) {
+        format!("FINAL ({} PENS)", score)
+    } else if competition.status.period >= 3 || competition.status.status_type.name.contains("AET") {
+        "FINAL (AET)".to_string()
+    } else {
+        "FINAL".to_string()
+    }
+}
+
+// away-home, like the score line
+fn shootout_score(competition: &Competition) -> Option<String> {
+    let (away, home) = away_home(competition)?;
+    Some(format!("{}-{}", away.shootout_score? as u32, home.shootout_score? as u32))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MatchEventKind {
+    Goal,
+    PenaltyGoal,
+    OwnGoal,
+    YellowCard,
+    RedCard,
+}
+
+#[derive(Debug, Clone)]
+struct MatchEvent {
+    kind: MatchEventKind,
+    minute: String,
+    player: String,
+    team: String,
+}
+
+impl MatchEvent {
+    fn symbol(&self) -> &'static str {
+        match self.kind {
+            MatchEventKind::Goal | MatchEventKind::PenaltyGoal | MatchEventKind::OwnGoal => "⚽",
+            MatchEventKind::YellowCard => "🟨",
+            MatchEventKind::RedCard => "🟥",
+        }
+    }
+
+    // i.e. "67' B. Saka (ARS, pen)"
+    fn label(&self) -> String {
+        let note = match self.kind {
+            MatchEventKind::PenaltyGoal => ", pen",
+            MatchEventKind::OwnGoal => ", og",
+            _ => "",
+        };
+        format!("{} {} ({}{})", self.minute, self.player, self.team, note)
+    }
+}
+
+// goals and cards in match order, the shootout is only in the score
+fn match_events(competition: &Competition) -> Vec<MatchEvent> {
+    let team_abbreviation = |id: &str| competition.competitors.iter()
+        .find(|competitor| competitor.team.id == id)
+        .map(|competitor| competitor.team.abbreviation.clone())
+        .unwrap_or_default();
+
+    competition.details.iter()
+        .filter(|detail| !detail.shootout)
+        .filter_map(|detail| {
+            let kind = if detail.own_goal {
+                MatchEventKind::OwnGoal
+            } else if detail.scoring_play && detail.penalty_kick {
+                MatchEventKind::PenaltyGoal
+            } else if detail.scoring_play {
+                MatchEventKind::Goal
+            } else if detail.red_card {
+                MatchEventKind::RedCard
+            } else if detail.yellow_card {
+                MatchEventKind::YellowCard
+            } else {
+                return None;
+            };
+            let athlete = detail.athletes_involved.first();
+            Some(MatchEvent {
+                kind,
+                minute: detail.clock.as_ref().map(|clock| clock.display_value.clone()).unwrap_or_default(),
+                player: athlete
+                    .map(|athlete| athlete.short_name.clone().unwrap_or_else(|| athlete.display_name.clone()))
+                    .or_else(|| detail.detail_type.as_ref().map(|kind| kind.text.to_lowercase()))
+                    .unwrap_or_default(),
+                team: detail.team.as_ref().map(|team| team_abbreviation(&team.id)).unwrap_or_default(),
+            })
+        })
+        .collect()
+}
+
+// fight cards
+
+// a fight night is one event with a competition per bout, each bout gets a card of its own.
+// espn lists them in the order they're fought, the card reads from the main event down
+fn split_fight_card(event: GameEvent) -> Vec<GameEvent> {
+    if event.competitions.len() < 2 || event.competitions.iter().any(|competition| away_home(competition).is_none()) {
+        return vec![event];
+    }
+    event.competitions.iter().rev().enumerate()
+        .map(|(position, competition)| {
+            let names: Vec<&str> = competition.competitors.iter().map(|competitor| competitor.short_name()).collect();
+            let heading = [
+                Some(event.short_name.as_str()),
+                competition.card_segment.as_ref().map(|segment| segment.description.as_str()),
+                weight_class(competition),
+            ];
+            GameEvent {
+                id: competition.id.clone(),
+                name: names.join(" vs "),
+                short_name: names.join(" vs "),
+                date: competition.date.clone(),
+                competitions: vec![competition.clone()],
+                groupings: Vec::new(),
+                tournament: Some(heading.into_iter().flatten().collect::<Vec<_>>().join(" · ")),
+                bout: Some(Bout { card: event.id.clone(), card_date: event.date.clone(), position }),
+                season: event.season.clone(),
+            }
+        })
+        .collect()
+}
+
+fn weight_class(competition: &Competition) -> Option<&str> {
+    let kind = competition.competition_type.as_ref()?;
+    kind.text.as_deref().or(kind.abbreviation.as_deref())
+}
+
+// "R2 3:12", the clock is the time into the round
+fn format_fight_clock(status: &Status) -> String {
+    format!("R{} {}", status.period.max(1), status.display_clock)
+}
+
+// "KO/TKO · R2 3:12", FINAL when espn doesn't say how
+fn format_fight_result(status: &Status) -> String {
+    let method = status.result.as_ref()
+        .and_then(|result| result.short_display_name.clone().or_else(|| result.display_name.clone()));
+    match method {
+        Some(method) => format!("{} · {}", method, format_fight_clock(status)),
+        None => "FINAL".to_string(),
+    }
+}
+
+// tennis
+
+// one event per match so each gets its own card, keeping the tournament and draw for its header
+fn split_groupings(event: GameEvent) -> Vec<GameEvent> {
+    if event.groupings.is_empty() {
+        return vec![event];
+    }
+    event.groupings.iter()
+        .flat_map(|grouping| grouping.competitions.iter().map(|competition| {
+            let mut competition = competition.clone();
+            count_sets(&mut competition);
+            let names: Vec<&str> = competition.competitors.iter().map(|competitor| competitor.short_name()).collect();
+            GameEvent {
+                id: competition.id.clone(),
+                name: names.join(" vs "),
+                short_name: names.join(" vs "),
+                date: competition.date.clone(),
+                competitions: vec![competition],
+                groupings: Vec::new(),
+                tournament: Some(format!("{} · {}", event.name, grouping.grouping.display_name)),
+                bout: None,
+                season: event.season.clone(),
+            }
+        }))
+        .collect()
+}
+
+// `score` becomes sets won, so score lines, alerts and the other outputs work like any sport.
+// whatever espn sent there mid-game is the game score
+fn count_sets(competition: &mut Competition) {
+    if competition.competitors.len() != 2 {
+        return;
+    }
+    // "0" could just as well be sets won, so one side has to be past love
+    let scores: Vec<&str> = competition.competitors.iter().map(|competitor| competitor.score.as_str()).collect();
+    let points = competition.status.status_type.state == "in"
+        && scores.iter().all(|score| is_game_score(score))
+        && scores.iter().any(|score| *score != "0");
+    let games: Vec<Vec<u32>> = competition.competitors.iter().map(set_games).collect();
+    for (index, competitor) in competition.competitors.iter_mut().enumerate() {
+        let won = games[index].iter().zip(&games[1 - index])
+            .filter(|(games, opponent)| is_set_won(**games, **opponent))
+            .count();
+        if points {
+            competitor.points = Some(competitor.score.clone());
+        }
+        competitor.score = won.to_string();
+    }
+}
+
+fn set_games(competitor: &Competitor) -> Vec<u32> {
+    competitor.linescores.iter().map(|set| set.value.unwrap_or(0.0) as u32).collect()
+}
+
+// six games and two clear, or a tiebreak
+fn is_set_won(games: u32, opponent: u32) -> bool {
+    (games >= 6 && games >= opponent + 2) || (games == 7 && opponent == 6)
+}
+
+fn is_game_score(score: &str) -> bool {
+    matches!(score, "0" | "15" | "30" | "40" | "A" | "AD")
+}
+
+// each player's games per set, "7(5)" for a tiebreak, padded so the two rows line up
+fn set_cells(away: &Competitor, home: &Competitor) -> [Vec<String>; 2] {
+    let cells = |competitor: &Competitor| -> Vec<String> {
+        competitor.linescores.iter()
+            .map(|set| match set.tiebreak {
+                Some(tiebreak) => format!("{}({})", set.value.unwrap_or(0.0) as u32, tiebreak),
+                None => (set.value.unwrap_or(0.0) as u32).to_string(),
+            })
+            .collect()
+    };
+    let (mut away_cells, mut home_cells) = (cells(away), cells(home));
+    for (away_cell, home_cell) in away_cells.iter_mut().zip(home_cells.iter_mut()) {
+        let width = away_cell.len().max(home_cell.len());
+        *away_cell = format!("{:<width$}", away_cell);
+        *home_cell = format!("{:<width$}", home_cell);
+    }
+    [away_cells, home_cells]
+}
+
+// "6-3 4-6 7-6(5)", from the first player's side
+fn format_set_scores(away: &Competitor, home: &Competitor) -> String {
+    away.linescores.iter().zip(&home.linescores)
+        .map(|(away_set, home_set)| {
+            let tiebreak = away_set.tiebreak.zip(home_set.tiebreak)
+                .map(|(away_points, home_points)| format!("({})", away_points.min(home_points)))
+                .unwrap_or_default();
+            format!("{}-{}{}", away_set.value.unwrap_or(0.0) as u32, home_set.value.unwrap_or(0.0) as u32, tiebreak)
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// "Wimbledon · Men's Singles · Quarterfinal"
+fn match_heading(event: &GameEvent, competition: &Competition) -> Option<String> {
+    let round = competition.round.as_ref().map(|round| round.display_name.as_str());
+    match (&event.tournament, round) {
+        (Some(tournament), Some(round)) => Some(format!("{} · {}", tournament, round)),
+        (Some(tournament), None) => Some(tournament.clone()),
+        (None, round) => round.map(str::to_string),
+    }
+}
+
+impl AppState {
+    // a row per player: serve marker, seed and name, then games per set
+    fn format_tennis_lines(&self, event: &GameEvent, away: &Competitor, home: &Competitor) -> Vec<Line<'static>> {
+        let hidden = self.hides_score(event);
+        let names = [away, home].map(|competitor| format!("{}{}", format_rank(competitor), competitor.short_name()));
+        let name_width = names.iter().map(|name| name.chars().count()).max().unwrap_or(0);
+        let live = GameGroup::of(event) == GameGroup::Live;
+
+        [away, home].into_iter().zip(names).zip(set_cells(away, home))
+            .map(|((competitor, name), cells)| {
+                let serving = if live && competitor.possession { "• " } else { "  " };
+                let sets = if hidden { HIDDEN_SCORE.to_string() } else { cells.join(" ") };
+                Line::from(vec![
+                    Span::styled(serving, self.theme.live),
+                    Span::styled(format!("{:<name_width$}  ", name), self.team_style(&competitor.team, None)),
+                    Span::styled(sets, self.theme.score),
+                ])
+            })
+            .collect()
+    }
+}
+
+// leaderboards
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct LeaderboardEntry {
+    position: String,
+    player: String,
+    #[serde(skip)]
+    short_name: String,
+    #[serde(flatten)]
+    standing: Standing,
+}
+
+// the columns after the player, which depend on the sport
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+enum Standing {
+    Golf { to_par: String, thru: String, today: String },
+    Race { gap: String, laps: String, status: String },
+}
+
+impl Standing {
+    fn cells(&self) -> [&str; 3] {
+        match self {
+            Standing::Golf { to_par, thru, today } => [to_par, thru, today],
+            Standing::Race { gap, laps, status } => [gap, laps, status],
+        }
+    }
+
+    fn hidden(&self) -> Self {
+        let hidden = || HIDDEN_SCORE.to_string();
+        match self {
+            Standing::Golf { .. } => Standing::Golf { to_par: hidden(), thru: hidden(), today: hidden() },
+            Standing::Race { .. } => Standing::Race { gap: hidden(), laps: hidden(), status: hidden() },
+        }
+    }
+}
+
+// race weekends have a competition per session: the one that's on, else the next one coming up
+// so a weekend between qualifying and the race isn't over yet, else the latest to finish
+fn current_competition(event: &GameEvent) -> Option<&Competition> {
+    let competitions = &event.competitions;
+    competitions.iter().find(|competition| competition.status.status_type.state == "in")
+        .or_else(|| competitions.iter().find(|competition| competition.status.status_type.state == "pre"))
+        .or_else(|| competitions.iter().rev().find(|competition| competition.status.status_type.state == "post"))
+        .or(competitions.first())
+}
+
+// a field of players or drivers rather than a matchup
+fn leaderboard_competition(event: &GameEvent) -> Option<&Competition> {
+    current_competition(event).filter(|competition| away_home(competition).is_none())
+}
+
+// every matchup, but only the current session of a race weekend
+fn shown_competitions(event: &GameEvent) -> Vec<&Competition> {
+    match leaderboard_competition(event) {
+        Some(competition) => vec![competition],
+        None => event.competitions.iter().collect(),
+    }
+}
+
+fn leaderboard_events<'a>(events: &[&'a GameEvent]) -> Vec<&'a GameEvent> {
+    events.iter().copied().filter(|event| leaderboard_competition(event).is_some()).collect()
+}
+
+// sort_by_key is stable, so anyone without an order keeps espn's
+fn running_order(competition: &Competition) -> Vec<&Competitor> {
+    let mut competitors: Vec<&Competitor> = competition.competitors.iter().collect();
+    competitors.sort_by_key(|competitor| competitor.order.unwrap_or(u32::MAX));
+    competitors
+}
+
+// "E" is even, anything that isn't a number (i.e. "CUT", "WD") has no position
+fn parse_to_par(score: &str) -> Option<i32> {
+    match score.trim() {
+        "E" => Some(0),
+        score => score.trim_start_matches('+').parse().ok(),
+    }
+}
+
+fn golf_leaderboard(competition: &Competition) -> Vec<LeaderboardEntry> {
+    let competitors = running_order(competition);
+    let status = &competition.status;
+    let started = status.status_type.state != "pre";
+    let scores: Vec<Option<i32>> = competitors.iter().map(|competitor| parse_to_par(&competitor.score)).collect();
+
+    competitors.iter().zip(&scores)
+        .map(|(competitor, score)| {
+            let position = match score.filter(|_| started) {
+                Some(score) => {
+                    let ahead = scores.iter().filter(|other| other.is_some_and(|other| other < score)).count();
+                    let tied = scores.iter().filter(|other| **other == Some(score)).count() > 1;
+                    format!("{}{}", if tied { "T" } else { "" }, ahead + 1)
+                }
+                None => "-".to_string(),
+            };
+
+            let round = competitor.linescores.iter().find(|line| line.period == Some(status.period));
+            let holes = round.map_or(0, |round| round.linescores.len());
+            let thru = if status.status_type.completed || holes >= 18 {
+                "F".to_string()
+            } else if holes == 0 {
+                "-".to_string()
+            } else {
+                holes.to_string()
+            };
+
+            LeaderboardEntry {
+                position,
+                player: competitor.display_name().to_string(),
+                short_name: competitor.short_name().to_string(),
+                standing: Standing::Golf {
+                    to_par: if competitor.score.is_empty() { "-".to_string() } else { competitor.score.clone() },
+                    thru,
+                    today: round.and_then(|round| round.display_value.clone()).unwrap_or_else(|| "-".to_string()),
+                },
+            }
+        })
+        .collect()
+}
+
+fn race_leaderboard(competition: &Competition) -> Vec<LeaderboardEntry> {
+    let started = competition.status.status_type.state != "pre";
+    running_order(competition).into_iter()
+        .map(|competitor| {
+            let position = competitor.order.filter(|_| started).map_or_else(|| "-".to_string(), |order| order.to_string());
+            // the leader has nobody to be behind, lapped cars are a number of laps down
+            let gap = match (competitor.stat("behindTime"), competitor.stat("behindLaps")) {
+                (Some(time), _) => time.to_string(),
+                (None, Some("1" | "+1")) => "+1 lap".to_string(),
+                (None, Some(laps)) if laps != "0" => format!("+{} laps", laps.trim_start_matches('+')),
+                _ if started && competitor.order == Some(1) => "leader".to_string(),
+                _ => "-".to_string(),
+            };
+            let laps = competitor.stat("lapsCompleted").or_else(|| competitor.stat("laps")).unwrap_or("-");
+            let status = competitor.stat("status").or_else(|| competitor.stat("reasonOut")).unwrap_or("-");
+
+            LeaderboardEntry {
+                position,
+                player: competitor.display_name().to_string(),
+                short_name: competitor.short_name().to_string(),
+                standing: Standing::Race { gap, laps: laps.to_string(), status: status.to_lowercase() },
+            }
+        })
+        .collect()
+}
+
+// "Miami Grand Prix · race", just the name for golf
+fn leaderboard_heading(event: &GameEvent, competition: &Competition) -> String {
+    match session_name(competition) {
+        Some(session) => format!("{} · {}", event.name, session),
+        None => event.name.clone(),
+    }
+}
+
+// "FP1" -> "practice 1", espn's abbreviation for anything else
+fn session_name(competition: &Competition) -> Option<String> {
+    // fights use the type for their weight class
+    if away_home(competition).is_some() {
+        return None;
+    }
+    let abbreviation = competition.competition_type.as_ref()?.abbreviation.as_deref()?;
+    let name = match abbreviation.to_lowercase().as_str() {
+        "fp1" | "fp2" | "fp3" => format!("practice {}", &abbreviation[2..]),
+        "qual" | "qualifying" => "qualifying".to_string(),
+        "sq" | "ss" => "sprint qualifying".to_string(),
+        "sr" | "sprint" => "sprint".to_string(),
+        "race" => "race".to_string(),
+        other => other.to_string(),
+    };
+    Some(name)
+}
+
+impl AppState {
+    fn leaderboard(&self, competition: &Competition) -> Vec<LeaderboardEntry> {
+        match find_league(&self.selected_league).map(|league| league.sport) {
+            Ok(Sport::Racing) => race_leaderboard(competition),
+            _ => golf_leaderboard(competition),
+        }
+    }
+
+    fn leaderboard_columns(&self) -> [&'static str; 3] {
+        match find_league(&self.selected_league).map(|league| league.sport) {
+            Ok(Sport::Racing) => ["gap", "laps", "status"],
+            _ => ["to par", "thru", "today"],
+        }
+    }
+
+    // the -t filter narrows the field down to the players it matches,
+    // and hidden results go alphabetical so the order doesn't give them away
+    fn shown_leaderboard(&self, event: &GameEvent, competition: &Competition) -> Vec<LeaderboardEntry> {
+        let mut entries: Vec<LeaderboardEntry> = self.leaderboard(competition).into_iter()
+            .filter(|entry| self.team_filter.as_ref().is_none_or(|filter| entry.player.to_lowercase().contains(&filter.to_lowercase())))
+            .collect();
+        if self.hides_score(event) {
+            entries.sort_by(|a, b| a.player.cmp(&b.player));
+            for entry in &mut entries {
+                entry.position = HIDDEN_SCORE.to_string();
+                entry.standing = entry.standing.hidden();
+            }
+        }
+        entries
+    }
+
+    fn is_favorite_player(&self, player: &str) -> bool {
+        let player = player.to_lowercase();
+        self.favorites.iter().any(|favorite| player.contains(&favorite.to_lowercase()))
+    }
+
+    // "1 S. Scheffler -12, T2 R. McIlroy -10"
+    fn format_leaders(&self, event: &GameEvent, competition: &Competition, count: usize) -> String {
+        self.shown_leaderboard(event, competition).into_iter()
+            .take(count)
+            .map(|entry| format!("{} {} {}", entry.position, entry.short_name, entry.standing.cells()[0]))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    // "The Masters · LIVE | Round 3"
+    fn format_leaderboard_title(&self, event: &GameEvent, competition: &Competition) -> String {
+        format!("{} · {}", leaderboard_heading(event, competition), plain_status(&self.format_status(competition)))
+    }
+}
+
+// playoff brackets
+
+const POSTSEASON: u32 = 3;
+// how far back a cup's bracket looks for the knockout rounds, and ahead for games already scheduled
+const BRACKET_DAYS_BACK: i64 = 90;
+const BRACKET_DAYS_AHEAD: i64 = 14;
+
+// rounds named after a conference share a column with the other conference's
+const CONFERENCE_PREFIXES: &[&str] = &[
+    "Eastern Conference ", "Western Conference ", "East ", "West ",
+    "AFC ", "NFC ", "American League ", "National League ", "AL ", "NL ",
+];
+// mlb puts the league in front of its abbreviations
+const LEAGUE_ROUNDS: &[(&str, &str)] = &[
+    ("ALWC", "Wild Card"), ("NLWC", "Wild Card"),
+    ("ALDS", "Division Series"), ("NLDS", "Division Series"),
+    ("ALCS", "Championship Series"), ("NLCS", "Championship Series"),
+];
+// soccer cups only say which stage a game is in through the season slug, i.e. "round-of-16"
+const KNOCKOUT_STAGES: &[&str] = &["playoff", "round-of", "final"];
+
+#[derive(Debug, Clone)]
+struct BracketSeries {
+    // "East 1st Round"
+    round: String,
+    // "1st Round", the column it's drawn in
+    stage: String,
+    started: Option<DateTime<Utc>>,
+    teams: [BracketTeam; 2],
+    // "CLE leads 3-2", none for single games and cup ties
+    summary: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct BracketTeam {
+    team: Team,
+    seed: Option<String>,
+    // games won in a series, otherwise the score, added up over both legs of a cup tie
+    wins: u32,
+    advanced: bool,
+}
+
+// only a playoff series is part of the bracket, a regular season series is just the next few games
+fn playoff_series(competition: &Competition) -> Option<&Series> {
+    competition.series.as_ref().filter(|series| series.series_type == "playoff")
+}
+
+// which game of a best-of series this is, and how many it can go
+fn series_game(competition: &Competition) -> Option<(u32, u32)> {
+    let series = playoff_series(competition).filter(|series| series.total_competitions > 1)?;
+    // the wins so far include this game once it's over
+    let played: u32 = series.competitors.iter().map(|competitor| competitor.wins).sum();
+    let game = if competition.status.status_type.state == "post" { played.max(1) } else { played + 1 };
+    Some((game, series.total_competitions))
+}
+
+// "East 1st Round · Game 5", and whether it's the game that decides the series
+fn series_heading(competition: &Competition) -> Option<(String, bool)> {
+    let series = playoff_series(competition).filter(|series| !series.title.is_empty())?;
+    match series_game(competition) {
+        Some((game, total)) => Some((format!("{} · Game {}", series.title, game), game == total)),
+        None => Some((series.title.clone(), false)),
+    }
+}
+
+// "CLE leads series 3-2" -> "CLE leads 3-2"
+fn series_summary(competition: &Competition) -> Option<String> {
+    let series = playoff_series(competition).filter(|series| !series.summary.is_empty())?;
+    Some(series.summary.replace(" series", "").replace("Series tied", "tied"))
+}
+
+// the round a postseason game belongs to, none in the regular season
+fn bracket_round(event: &GameEvent, competition: &Competition) -> Option<String> {
+    if let Some(series) = playoff_series(competition)
+        && !series.title.is_empty() {
+        return Some(series.title.clone());
+    }
+    let season = event.season.as_ref()?;
+    if season.season_type == POSTSEASON {
+        // "AFC Wild Card Playoffs", some leagues add " - Game 1"
+        let headline = competition.notes.first()?.headline.split(" - ").next()?.trim();
+        return (!headline.is_empty()).then(|| headline.to_string());
+    }
+    KNOCKOUT_STAGES.iter()
+        .any(|stage| season.slug.contains(stage))
+        .then(|| season.slug.replace('-', " "))
+}
+
+fn bracket_stage(round: &str) -> String {
+    if let Some((_, stage)) = LEAGUE_ROUNDS.iter().find(|(abbreviation, _)| *abbreviation == round) {
+        return stage.to_string();
+    }
+    CONFERENCE_PREFIXES.iter()
+        .find_map(|prefix| round.strip_prefix(prefix))
+        .unwrap_or(round)
+        .to_string()
+}
+
+// one entry per matchup, built up game by game so the latest game has the final say.
+// cup ties are played over two legs, so winning one doesn't mean going through
+fn build_bracket(events: &[GameEvent], aggregate: bool) -> Vec<BracketSeries> {
+    let mut games: Vec<(&GameEvent, &Competition)> = events.iter()
+        .flat_map(|event| event.competitions.iter().map(move |competition| (event, competition)))
+        .collect();
+    games.sort_by_key(|(_, competition)| parse_event_date(&competition.date));
+
+    let mut bracket: Vec<BracketSeries> = Vec::new();
+    for (event, competition) in games {
+        let (Some(round), Some((away, home))) = (bracket_round(event, competition), away_home(competition)) else {
+            continue;
+        };
+        let index = match bracket.iter().position(|series| series.round == round
+            && [away, home].iter().all(|competitor| series.teams.iter().any(|team| team.team.id == competitor.team.id))) {
+            Some(index) => index,
+            None => {
+                bracket.push(BracketSeries {
+                    stage: bracket_stage(&round),
+                    round,
+                    started: parse_event_date(&competition.date),
+                    teams: [away, home].map(|competitor| BracketTeam {
+                        team: competitor.team.clone(),
+                        seed: competitor.seed(),
+                        wins: 0,
+                        advanced: false,
+                    }),
+                    summary: None,
+                });
+                bracket.len() - 1
+            }
+        };
+
+        let entry = &mut bracket[index];
+        for competitor in [away, home] {
+            let Some(team) = entry.teams.iter_mut().find(|team| team.team.id == competitor.team.id) else {
+                continue;
+            };
+            match playoff_series(competition) {
+                Some(series) => {
+                    team.wins = series.competitors.iter()
+                        .find(|series_competitor| series_competitor.id == competitor.team.id)
+                        .map_or(0, |series_competitor| series_competitor.wins);
+                    let most = series.competitors.iter().map(|series_competitor| series_competitor.wins).max().unwrap_or(0);
+                    team.advanced = (series.total_competitions > 0 && team.wins > series.total_competitions / 2)
+                        || (series.completed && team.wins == most);
+                }
+                None => {
+                    team.wins += competitor.score.parse::<u32>().unwrap_or(0);
+                    team.advanced = competitor.advance || (competitor.winner && !aggregate);
+                }
+            }
+            if team.seed.is_none() {
+                team.seed = competitor.seed();
+            }
+        }
+        entry.summary = series_summary(competition);
+    }
+    bracket
+}
+
+// stages left to right in the order they started, each with its matchups
+fn bracket_stages(bracket: &[BracketSeries]) -> Vec<(&str, Vec<&BracketSeries>)> {
+    let mut stages: Vec<(&str, Vec<&BracketSeries>)> = Vec::new();
+    let mut ordered: Vec<&BracketSeries> = bracket.iter().collect();
+    ordered.sort_by_key(|series| series.started);
+    for series in ordered {
+        match stages.iter_mut().find(|(stage, _)| *stage == series.stage) {
+            Some((_, matchups)) => matchups.push(series),
+            None => stages.push((&series.stage, vec![series])),
+        }
+    }
+    // east above west, sort_by_key is stable so each side keeps its start order
+    for (_, matchups) in &mut stages {
+        matchups.sort_by_key(|series| &series.round);
+    }
+    stages
+}
+
+impl AppState {
+    fn toggle_bracket(&mut self) {
+        self.show_bracket = !self.show_bracket;
+        self.detail_open = false;
+        self.scroll_offset = 0;
+    }
+
+    // the bracket scrolls a matchup at a time, as far as its longest column goes
+    fn scroll_total(&self, events: &[&GameEvent]) -> usize {
+        match (self.show_bracket, &self.bracket) {
+            (true, Some(bracket)) => bracket_stages(bracket).iter().map(|(_, matchups)| matchups.len()).max().unwrap_or(0),
+            (true, None) => 0,
+            (false, _) => self.scroll_len(events),
+        }
+    }
+
+    // how far there is to scroll: games, or the rows of the longest leaderboard left by -t
+    fn scroll_len(&self, events: &[&GameEvent]) -> usize {
+        let boards = leaderboard_events(events);
+        if boards.is_empty() {
+            return events.len();
+        }
+        boards.iter()
+            .filter_map(|event| leaderboard_competition(event).map(|competition| self.shown_leaderboard(event, competition).len()))
+            .max()
+            .unwrap_or(0)
+    }
+
+    // like hides_score, but for a whole series. revealing a game doesn't reveal its series
+    fn hides_series(&self, series: &BracketSeries) -> bool {
+        let spoilers = &self.spoilers;
+        if !spoilers.enabled {
+            return false;
+        }
+        if spoilers.teams.is_empty() && spoilers.leagues.is_empty() {
+            return true;
+        }
+        spoilers.leagues.iter().any(|league| same_league(league, &self.selected_league)) ||
+        spoilers.teams.iter().any(|filter| series.teams.iter().any(|team| team_matches(&team.team, filter)))
+    }
+
+    // who plays in a later round says who won the one before, so those only keep their place
+    fn hides_later_rounds(&self, bracket: &[BracketSeries]) -> bool {
+        bracket.iter().any(|series| self.hides_series(series))
+    }
+}
+
+// selection, details and league tabs
+
+// what a mouse click landed on, recorded while drawing
+#[derive(Debug, Clone, PartialEq)]
+enum ClickTarget {
+    Game(String),
+    League(usize),
+    Log,
+}
+
+// players listed in the detail popup of a leaderboard
+const DETAIL_LEADERS: usize = 10;
+// fewer per session when a race weekend lists them all
+const SESSION_LEADERS: usize = 3;
+
+impl AppState {
+    fn selected_event(&self) -> Option<&GameEvent> {
+        let selected = self.selected.as_ref()?;
+        self.events.iter().find(|event| &event.id == selected)
+    }
+
+    fn is_selected(&self, event: &GameEvent) -> bool {
+        self.selected.as_ref() == Some(&event.id)
+    }
+
+    // a second click on the selected game opens it
+    fn click_game(&mut self, event_id: &str) {
+        if self.selected.as_deref() == Some(event_id) {
+            self.detail_open = true;
+        } else {
+            self.selected = Some(event_id.to_string());
+        }
+    }
+
+    fn move_selection(&mut self, step: isize) {
+        let ids: Vec<String> = self.get_filtered_events().iter().map(|event| event.id.clone()).collect();
+        if ids.is_empty() {
+            return;
+        }
+        let index = match self.selected.as_ref().and_then(|id| ids.iter().position(|other| other == id)) {
+            Some(index) => (index as isize + step).clamp(0, ids.len() as isize - 1) as usize,
+            None => self.scroll_offset.min(ids.len() - 1),
+        };
+        self.selected = Some(ids[index].clone());
+    }
+
+    fn open_detail(&mut self) {
+        self.detail_open = self.selected_event().is_some();
+    }
+
+    fn close_detail(&mut self) {
+        self.detail_open = false;
+    }
+
+    fn click_target(&self, column: u16, row: u16) -> Option<ClickTarget> {
+        self.click_targets.iter()
+            .find(|(area, _)| area.contains(Position { x: column, y: row }))
+            .map(|(_, target)| target.clone())
+    }
+
+    // false when it's already the current league, so there's nothing to fetch
+    fn select_league(&mut self, index: usize) -> bool {
+        let Some(league) = self.leagues.get(index) else {
+            return false;
+        };
+        if *league == self.selected_league {
+            return false;
+        }
+        self.selected_league = league.clone();
+        self.events.clear();
+        self.bracket = None;
+        self.bracket_error = None;
+        self.pending.clear();
+        self.selected = None;
+        self.detail_open = false;
+        self.scroll_offset = 0;
+        self.error_message = None;
+        true
+    }
+
+    fn cycle_league(&mut self, step: isize) -> bool {
+        let current = self.leagues.iter().position(|league| *league == self.selected_league).unwrap_or(0);
+        let count = self.leagues.len() as isize;
+        self.select_league((current as isize + step).rem_euclid(count) as usize)
+    }
+
+    fn format_game_detail(&self, event: &GameEvent) -> Vec<Line<'static>> {
+        let mut lines = Vec::new();
+        for competition in &event.competitions {
+            let status = &competition.status.status_type;
+            let group = GameGroup::of_competition(competition);
+            let detail = self.shown_detail(event, competition, &status.detail);
+            let detail = match session_name(competition) {
+                Some(session) => format!("{}: {}", session, detail),
+                None => detail,
+            };
+            if let Some(heading) = self.shown_series_heading(event, competition).map(|(heading, _)| heading).or_else(|| match_heading(event, competition)) {
+                lines.push(Line::from(Span::styled(heading, self.theme.text)));
+            }
+            lines.push(Line::from(Span::styled(detail, get_group_style(&self.theme, group))));
+            if group == GameGroup::Upcoming
+                && let Some(start) = parse_event_date(&competition.date) {
+                lines.push(Line::from(Span::styled(self.format_kickoff(start), self.theme.dim)));
+            }
+            lines.push(Line::from(""));
+
+            if let Some((away, home)) = away_home(competition) {
+                for competitor in [away, home] {
+                    let mut spans = vec![
+                        Span::styled(format!("{:<5}", competitor.team.abbreviation), self.team_style(&competitor.team, None)),
+                        Span::styled(format!("{:<26}", format!("{}{}", format_rank(competitor), competitor.display_name())), self.theme.text),
+                        Span::styled(format!("{:>3}", self.shown_score(event, competitor)), self.theme.score),
+                    ];
+                    if let Some(hits) = competitor.hits.filter(|_| !self.hides_score(event)) {
+                        spans.push(Span::styled(format!("  {} h", hits), self.theme.dim));
+                    }
+                    lines.push(Line::from(spans));
+
+                    let records = competitor.records.iter()
+                        .filter(|_| !self.hides_score(event))
+                        .map(|record| format!("{} {}", record.summary, record.name.to_lowercase()))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    if !records.is_empty() {
+                        lines.push(Line::from(Span::styled(format!("     {}", records), self.theme.dim)));
+                    }
+                }
+                if self.is_fight() && !self.hides_score(event)
+                    && let Some(winner) = [away, home].into_iter().find(|competitor| competitor.winner) {
+                    lines.push(Line::from(""));
+                    lines.push(Line::from(Span::styled(
+                        format!("{} wins by {}", winner.display_name(), format_fight_result(&competition.status)),
+                        self.theme.text
+                    )));
+                }
+                if self.is_tennis() && !self.hides_score(event) {
+                    lines.push(Line::from(""));
+                    lines.push(Line::from(Span::styled(format!("sets: {}", format_set_scores(away, home)), self.theme.text)));
+                }
+                if !self.hides_score(event)
+                    && let Some(summary) = series_summary(competition) {
+                    lines.push(Line::from(""));
+                    lines.push(Line::from(Span::styled(format!("series: {}", summary), self.theme.text)));
+                }
+            } else {
+                // the leaders, the main view has the whole field
+                let leaders = if event.competitions.len() > 1 { SESSION_LEADERS } else { DETAIL_LEADERS };
+                for entry in self.shown_leaderboard(event, competition).into_iter().take(leaders) {
+                    lines.push(Line::from(vec![
+                        Span::styled(format!("{:<5}", entry.position), self.theme.dim),
+                        Span::styled(format!("{:<26}", entry.player), self.theme.text),
+                        Span::styled(format!("{:>4}", entry.standing.cells()[0]), self.theme.score),
+                        Span::styled(format!("  {:>3}  {}", entry.standing.cells()[1], entry.standing.cells()[2]), self.theme.dim),
+                    ]));
+                }
+            }
+
+            // goals give the score away too
+            let match_events = if self.hides_score(event) { Vec::new() } else { match_events(competition) };
+            if !match_events.is_empty() {
+                lines.push(Line::from(""));
+            }
+            for match_event in match_events {
+                let style = match match_event.kind {
+                    MatchEventKind::RedCard => self.theme.live,
+                    MatchEventKind::YellowCard => self.theme.dim,
+                    _ => self.theme.text,
+                };
+                lines.push(Line::from(Span::styled(format!("{} {}", match_event.symbol(), match_event.label()), style)));
+            }
+
+            let broadcasts = competition.broadcasts.iter()
+                .flat_map(|broadcast| broadcast.names.iter().cloned())
+                .collect::<Vec<_>>();
+            if !broadcasts.is_empty() {
+                lines.push(Line::from(""));
+                lines.push(Line::from(Span::styled(format!("tv: {}", broadcasts.join(", ")), self.theme.dim)));
+            }
+        }
+        lines
+    }
+}
+
+// keybindings
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Action {
+    Quit,
+    Refresh,
+    Search,
+    Clear,
+    Sort,
+    Headers,
+    Layout,
+    Log,
+    LogUp,
+    LogDown,
+    ScrollUp,
+    ScrollDown,
+    PageUp,
+    PageDown,
+    Top,
+    Bottom,
+    SelectPrevious,
+    SelectNext,
+    Details,
+    Reveal,
+    Bracket,
+    NextLeague,
+    PreviousLeague,
+    Help,
+}
+
+impl Action {
+    // the order the help overlay lists them in
+    const ALL: [Action; 24] = [
+        Action::ScrollDown, Action::ScrollUp, Action::PageDown, Action::PageUp, Action::Top, Action::Bottom,
+        Action::SelectPrevious, Action::SelectNext, Action::Details, Action::Reveal, Action::Bracket, Action::NextLeague, Action::PreviousLeague,
+        Action::Search, Action::Clear, Action::Sort, Action::Headers, Action::Layout,
+        Action::Log, Action::LogUp, Action::LogDown, Action::Refresh, Action::Help, Action::Quit,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::Refresh => "force refresh",
+            Action::Search => "filter by team or status",
+            Action::Clear => "close details or clear filter",
+            Action::Sort => "cycle sort mode",
+            Action::Headers => "toggle live/upcoming/final headers",
+            Action::Layout => "toggle cards/compact table layout",
+            Action::Log => "toggle score change log",
+            Action::LogUp => "scroll score change log up",
+            Action::LogDown => "scroll score change log down",
+            Action::ScrollUp => "scroll up",
+            Action::ScrollDown => "scroll down",
+            Action::PageUp => "page up",
+            Action::PageDown => "page down",
+            Action::Top => "jump to the top",
+            Action::Bottom => "jump to the bottom",
+            Action::SelectPrevious => "select previous game",
+            Action::SelectNext => "select next game",
+            Action::Details => "show details for the selected game",
+            Action::Reveal => "reveal or hide the selected game's score",
+            Action::Bracket => "toggle playoff bracket",
+            Action::NextLeague => "next league",
+            Action::PreviousLeague => "previous league",
+            Action::Help => "show this help",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    // "q", "G", "ctrl-d", "alt-v", "pageup", "shift-tab", ...
+    fn parse(value: &str) -> Result<Self, Box<dyn Error>> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut rest = value;
+        loop {
+            let lower = rest.to_lowercase();
+            let prefix = ["ctrl-", "c-", "alt-", "m-", "shift-", "s-"].into_iter()
+                .find(|prefix| lower.starts_with(prefix) && rest.len() > prefix.len());
+            let Some(prefix) = prefix else {
+                break;
+            };
+            modifiers |= match prefix {
+                "ctrl-" | "c-" => KeyModifiers::CONTROL,
+                "alt-" | "m-" => KeyModifiers::ALT,
+                _ => KeyModifiers::SHIFT,
+            };
+            rest = &rest[prefix.len()..];
+        }
+
+        let code = match rest.to_lowercase().as_str() {
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "pageup" | "pgup" => KeyCode::PageUp,
+            "pagedown" | "pgdn" => KeyCode::PageDown,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "backspace" => KeyCode::Backspace,
+            "space" => KeyCode::Char(' '),
+            _ => {
+                let mut chars = rest.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => return Err(format!("unknown key: {}", value).into()),
+                }
+            }
+        };
+        Ok(KeyBinding::new(code, modifiers))
+    }
+
+    // shift is already part of the character (G, ?) and of backtab
+    fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let modifiers = match code {
+            KeyCode::Char(_) | KeyCode::BackTab => modifiers - KeyModifiers::SHIFT,
+            _ => modifiers,
+        };
+        Self { code, modifiers }
+    }
+
+    fn label(&self) -> String {
+        let key = match self.code {
+            KeyCode::Up => "↑".to_string(),
+            KeyCode::Down => "↓".to_string(),
+            KeyCode::Left => "←".to_string(),
+            KeyCode::Right => "→".to_string(),
+            KeyCode::PageUp => "pgup".to_string(),
+            KeyCode::PageDown => "pgdn".to_string(),
+            KeyCode::Home => "home".to_string(),
+            KeyCode::End => "end".to_string(),
+            KeyCode::Enter => "enter".to_string(),
+            KeyCode::Esc => "esc".to_string(),
+            KeyCode::Tab => "tab".to_string(),
+            KeyCode::BackTab => "shift-tab".to_string(),
+            KeyCode::Backspace => "backspace".to_string(),
+            KeyCode::Char(' ') => "space".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            code => format!("{:?}", code).to_lowercase(),
+        };
+        let mut label = String::new();
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            label.push_str("ctrl-");
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            label.push_str("alt-");
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            label.push_str("shift-");
+        }
+        label + &key
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum KeyList {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeyList {
+    fn keys(&self) -> Vec<&str> {
+        match self {
+            KeyList::One(key) => vec![key.as_str()],
+            KeyList::Many(keys) => keys.iter().map(|key| key.as_str()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct KeysConfig {
+    preset: Option<String>,
+    #[serde(flatten)]
+    bindings: HashMap<Action, KeyList>,
+}
+
+#[derive(Debug, Clone)]
+struct Keymap {
+    preset: String,
+    bindings: Vec<(KeyBinding, Action)>,
+}
+
+const DEFAULT_BINDINGS: &[(Action, &[&str])] = &[
+    (Action::Quit, &["q", "ctrl-c"]),
+    (Action::Refresh, &["r"]),
+    (Action::Search, &["/"]),
+    (Action::Clear, &["esc"]),
+    (Action::Sort, &["s"]),
+    (Action::Headers, &["h"]),
+    (Action::Layout, &["c"]),
+    (Action::Log, &["e"]),
+    (Action::LogUp, &["["]),
+    (Action::LogDown, &["]"]),
+    (Action::ScrollUp, &["up", "k"]),
+    (Action::ScrollDown, &["down", "j"]),
+    (Action::PageUp, &["pageup"]),
+    (Action::PageDown, &["pagedown"]),
+    (Action::Top, &["home", "g"]),
+    (Action::Bottom, &["end", "G"]),
+    (Action::SelectPrevious, &["left"]),
+    (Action::SelectNext, &["right"]),
+    (Action::Details, &["enter"]),
+    (Action::Reveal, &["x"]),
+    (Action::Bracket, &["b"]),
+    (Action::NextLeague, &["tab"]),
+    (Action::PreviousLeague, &["shift-tab"]),
+    (Action::Help, &["?"]),
+];
+
+// presets only list what they change from the defaults
+const VIM_BINDINGS: &[(Action, &[&str])] = &[
+    (Action::ScrollUp, &["k", "up"]),
+    (Action::ScrollDown, &["j", "down"]),
+    (Action::PageUp, &["ctrl-u", "ctrl-b", "pageup"]),
+    (Action::PageDown, &["ctrl-d", "ctrl-f", "pagedown"]),
+    (Action::Top, &["g", "home"]),
+    (Action::Bottom, &["G", "end"]),
+    (Action::SelectPrevious, &["h", "left"]),
+    (Action::SelectNext, &["l", "right"]),
+    (Action::LogUp, &["ctrl-y", "["]),
+    (Action::LogDown, &["ctrl-e", "]"]),
+    (Action::NextLeague, &["L", "tab"]),
+    (Action::PreviousLeague, &["H", "shift-tab"]),
+    (Action::Headers, &["z"]),
+];
+
+const EMACS_BINDINGS: &[(Action, &[&str])] = &[
+    (Action::Quit, &["q", "ctrl-c", "ctrl-x"]),
+    (Action::Search, &["ctrl-s", "/"]),
+    (Action::Clear, &["ctrl-g", "esc"]),
+    (Action::ScrollUp, &["ctrl-p", "up"]),
+    (Action::ScrollDown, &["ctrl-n", "down"]),
+    (Action::PageUp, &["alt-v", "pageup"]),
+    (Action::PageDown, &["ctrl-v", "pagedown"]),
+    (Action::Top, &["alt-<", "home"]),
+    (Action::Bottom, &["alt->", "end"]),
+    (Action::SelectPrevious, &["ctrl-b", "left"]),
+    (Action::SelectNext, &["ctrl-f", "right"]),
+    (Action::Refresh, &["ctrl-l", "r"]),
+];
+
+impl Keymap {
+    fn from_config(config: &KeysConfig) -> Result<Self, Box<dyn Error>> {
+        let preset = config.preset.as_deref().unwrap_or("default").to_lowercase();
+        let mut keymap = Keymap { preset: preset.clone(), bindings: Vec::new() };
+        keymap.apply(DEFAULT_BINDINGS.iter().map(|(action, keys)| (*action, keys.to_vec())))?;
+
+        match preset.as_str() {
+            "default" => {}
+            "vim" => keymap.apply(VIM_BINDINGS.iter().map(|(action, keys)| (*action, keys.to_vec())))?,
+            "emacs" => keymap.apply(EMACS_BINDINGS.iter().map(|(action, keys)| (*action, keys.to_vec())))?,
+            _ => return Err(format!("unknown key preset: {} (default, vim, emacs)", preset).into()),
+        }
+
+        keymap.apply(config.bindings.iter().map(|(action, keys)| (*action, keys.keys())))?;
+        Ok(keymap)
+    }
+
+    // replaces every key of each action, and takes the keys away from anything else
+    fn apply<'a>(&mut self, bindings: impl Iterator<Item = (Action, Vec<&'a str>)>) -> Result<(), Box<dyn Error>> {
+        for (action, keys) in bindings {
+            let keys = keys.into_iter()
+                .map(KeyBinding::parse)
+                .collect::<Result<Vec<_>, _>>()?;
+            self.bindings.retain(|(key, bound)| *bound != action && !keys.contains(key));
+            self.bindings.extend(keys.into_iter().map(|key| (key, action)));
+        }
+        Ok(())
+    }
+
+    fn action(&self, key: &KeyEvent) -> Option<Action> {
+        let pressed = KeyBinding::new(key.code, key.modifiers);
+        self.bindings.iter()
+            .find(|(binding, _)| *binding == pressed)
+            .map(|(_, action)| *action)
+    }
+
+    fn keys(&self, action: Action) -> Vec<String> {
+        self.bindings.iter()
+            .filter(|(_, bound)| *bound == action)
+            .map(|(key, _)| key.label())
+            .collect()
+    }
+
+    // the first key of an action, for the footer
+    fn hint(&self, action: Action) -> String {
+        self.keys(action).into_iter().next().unwrap_or_else(|| "-".to_string())
+    }
+}
+
+// ui layout
+
+impl AppState {
+    fn calculate_games_per_screen(&self, events: &[&GameEvent], content_width: u16, content_height: u16) -> usize {
+        if self.layout_mode == LayoutMode::Compact {
+            return self.calculate_compact_rows(events, content_height);
+        }
+
+        // leave room for a header and margin above each of the three groups
+        let content_height = if self.group_games {
+            content_height.saturating_sub(GROUP_HEADER_HEIGHT * 3)
+        } else {
+            content_height
+        };
+        let games_per_column = (content_height / 6).max(1) as usize; // roughly 6 lines per boxed game
+        
+        games_per_column * self.calculate_grid_columns(content_width)
+    }
+
+    // a game can take more than one row (a row per competition, soccer's goal lines) and so can
+    // the group headers, so this counts how many games from the current one fit row by row
+    fn calculate_compact_rows(&self, events: &[&GameEvent], content_height: u16) -> usize {
+        let mut free = content_height.saturating_sub(COMPACT_TABLE_CHROME) as usize;
+        let mut current_group = None;
+        let mut fits = 0;
+        for event in events.iter().skip(self.scroll_offset) {
+            let group = GameGroup::of(event);
+            let header = usize::from(self.group_games && current_group != Some(group));
+            let rows = header + self.format_game_row(event).len();
+            if rows > free {
+                break;
+            }
+            free -= rows;
+            current_group = Some(group);
+            fits += 1;
+        }
+        fits.max(1)
+    }
+
+    fn calculate_grid_columns(&self, content_width: u16) -> usize {
+        match self.layout_mode {
+            LayoutMode::Compact => 1,
+            LayoutMode::Cards => (content_width / MIN_CARD_WIDTH).clamp(1, MAX_GRID_COLUMNS) as usize,
+        }
+    }
+}
+
+// leaderboards split the screen, each with a border and a column header
+fn calculate_leaderboard_rows(content_height: u16, boards: usize) -> usize {
+    (content_height / boards as u16).saturating_sub(COMPACT_TABLE_CHROME).max(1) as usize
+}
+
+// matchups that fit in a bracket column under its heading, at three rows each with only a top border
+fn calculate_bracket_rows(content_height: u16) -> usize {
+    (content_height.saturating_sub(1) / 3).max(1) as usize
+}
+
+// narrowest a card gets before the grid drops a column
+const MIN_CARD_WIDTH: u16 = 40;
+const MAX_GRID_COLUMNS: u16 = 5;
+
+// ui render
+
+async fn render_scoreboard(app: &mut AppState) -> Result<(), Box<dyn Error>> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    loop {
+        // check if we need to auto-refresh
+        if app.should_refresh() && !app.is_refreshing
+            && let Err(e) = app.fetch_data().await {
+            app.error_message = Some(format!("refresh failed: {}", e));
+        }
+        app.release_delayed();
+
+        let mut grid_columns = app.grid_columns;
+        let mut page_size = app.page_size;
+        let mut click_targets = Vec::new();
+        terminal.draw(|f| {
+            let chunks = create_main_layout(f.area());
+            let (games_area, log_area) = split_log_pane(&chunks[1], app.show_log);
+            let filtered_events = app.get_filtered_events();
+            let content_width = games_area.width;
+            let content_height = games_area.height;
+            let boards = leaderboard_events(&filtered_events);
+            let total_games_per_screen = if app.show_bracket {
+                calculate_bracket_rows(content_height)
+            } else if boards.is_empty() {
+                app.calculate_games_per_screen(&filtered_events, content_width, content_height)
+            } else {
+                calculate_leaderboard_rows(content_height, boards.len())
+            };
+            grid_columns = if boards.is_empty() && !app.show_bracket { app.calculate_grid_columns(content_width) } else { 1 };
+            page_size = total_games_per_screen;
+
+            // render header
+            render_header(f, &chunks[0], app, &mut click_targets);
+
+            // render main content
+            render_main_content(f, &games_area, app, &filtered_events, content_width, total_games_per_screen, &mut click_targets);
+
+            // render event log
+            if let Some(log_area) = log_area {
+                render_event_log(f, &log_area, app);
+                click_targets.push((log_area, ClickTarget::Log));
+            }
+
+            // render footer
+            render_footer(f, &chunks[2], app, &filtered_events, total_games_per_screen);
+
+            // render the detail popup over everything else
+            if app.detail_open
+                && let Some(event) = app.selected_event() {
+                render_game_detail(f, f.area(), app, event);
+            }
+            if app.show_help {
+                render_help(f, f.area(), app);
+            }
+        })?;
+        app.grid_columns = grid_columns;
+        app.page_size = page_size;
+        app.click_targets = click_targets;
+
+        // handle input with timeout for refresh checking
+        if event::poll(Duration::from_millis(500))? {
+            let exit = match event::read()? {
+                Event::Key(key) => handle_input(key, app).await?,
+                Event::Mouse(mouse) => {
+                    handle_mouse(mouse, app).await;
+                    false
+                }
+                _ => false,
+            };
+            if exit {
+                break; // exit requested
+            }
+        }
+    }
+    
+    cleanup_terminal(&mut terminal)?;
+    Ok(())
+}
+
+fn create_main_layout(area: ratatui::layout::Rect) -> Vec<ratatui::layout::Rect> {
+    Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(8),
+            Constraint::Length(3),
+        ].as_ref())
+        .split(area).to_vec()
+}
+
+const LOG_PANE_HEIGHT: u16 = 8;
+
+fn split_log_pane(area: &ratatui::layout::Rect, show_log: bool) -> (ratatui::layout::Rect, Option<ratatui::layout::Rect>) {
+    if !show_log {
+        return (*area, None);
+    }
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(6), Constraint::Length(LOG_PANE_HEIGHT)])
+        .split(*area);
+    (chunks[0], Some(chunks[1]))
+}
+
+fn render_event_log(f: &mut ratatui::Frame, area: &ratatui::layout::Rect, app: &AppState) {
+    let lines: Vec<Line> = if app.event_log.is_empty() {
+        vec![Line::from(Span::styled("no score changes yet", app.theme.dim))]
+    } else {
+        app.event_log.iter()
+            .skip(app.log_scroll)
+            .map(|entry| {
+                let style = if entry.time > Utc::now() - chrono::Duration::from_std(FLASH_DURATION).unwrap_or_default() {
+                    app.theme.accent
+                } else {
+                    app.theme.text
+                };
+                Line::from(Span::styled(entry.text.clone(), style))
+            })
+            .collect()
+    };
+
+    let title = format!(" events ({}) ", app.event_log.len());
+    let log = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title(title).border_style(app.theme.dim));
+    f.render_widget(log, *area);
+}
+
+fn render_header(f: &mut ratatui::Frame, area: &ratatui::layout::Rect, app: &AppState, targets: &mut Vec<(ratatui::layout::Rect, ClickTarget)>) {
+    if app.leagues.len() > 1 {
+        render_league_tabs(f, area, app, targets);
+        return;
+    }
+
+    let title = match &app.team_filter {
+        Some(team) => format!("scrbrd | {}", team.to_lowercase()),
+        None => match app.conference {
+            Some(ref conference) if is_college_league(&app.selected_league) => {
+                format!("scrbrd | {} | {}", app.selected_league.to_lowercase(), conference.to_lowercase())
+            }
+            _ => format!("scrbrd | {}", league_name(&app.selected_league)),
+        },
+    };
+    
+    let header = Paragraph::new(title)
+        .style(app.theme.title)
+        .alignment(Alignment::Center) 
+        .block(Block::default());
+    f.render_widget(header, *area);
+}
+
+fn render_league_tabs(f: &mut ratatui::Frame, area: &ratatui::layout::Rect, app: &AppState, targets: &mut Vec<(ratatui::layout::Rect, ClickTarget)>) {
+    let mut spans = vec![Span::styled("scrbrd | ", app.theme.title)];
+    let mut tabs = Vec::new();
+    let mut x = spans[0].width() as u16;
+    for (index, league) in app.leagues.iter().enumerate() {
+        if index > 0 {
+            spans.push(Span::styled(" │ ", app.theme.dim));
+            x += 3;
+        }
+        let label = format!(" {} ", league.to_lowercase());
+        let style = if *league == app.selected_league {
+            app.theme.title.add_modifier(Modifier::REVERSED)
+        } else {
+            app.theme.dim
+        };
+        tabs.push((x, label.len() as u16, index));
+        x += label.len() as u16;
+        spans.push(Span::styled(label, style));
+    }
+
+    // centered, so the tabs start half the leftover width in
+    let left = area.x + area.width.saturating_sub(x) / 2;
+    for (offset, width, index) in tabs {
+        let tab_area = ratatui::layout::Rect { x: left + offset, y: area.y, width, height: 1 };
+        targets.push((tab_area.intersection(*area), ClickTarget::League(index)));
+    }
+    f.render_widget(Paragraph::new(Line::from(spans)).alignment(Alignment::Center), *area);
+}
+
+fn render_game_detail(f: &mut ratatui::Frame, area: ratatui::layout::Rect, app: &AppState, event: &GameEvent) {
+    let lines = app.format_game_detail(event);
+    let width = 60.min(area.width.saturating_sub(4));
+    let height = (lines.len() as u16 + 4).min(area.height.saturating_sub(2));
+    let popup = ratatui::layout::Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Thick)
+        .border_style(app.theme.accent)
+        .title(format!(" {} ", event.name))
+        .title_bottom(Line::from(" esc: close ").alignment(Alignment::Right))
+        .padding(ratatui::widgets::Padding::uniform(1));
+    f.render_widget(Clear, popup);
+    f.render_widget(Paragraph::new(lines).block(block), popup);
+}
+
+fn render_help(f: &mut ratatui::Frame, area: ratatui::layout::Rect, app: &AppState) {
+    let mut lines: Vec<Line> = Action::ALL.iter()
+        .map(|&action| {
+            let keys = app.keymap.keys(action);
+            let keys = if keys.is_empty() { "-".to_string() } else { keys.join(" ") };
+            Line::from(vec![
+                Span::styled(format!("{:>18}  ", keys), app.theme.accent),
+                Span::styled(action.label(), app.theme.text),
+            ])
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("mouse: wheel scrolls, click selects, click again for details", app.theme.dim)));
+
+    let width = 64.min(area.width.saturating_sub(4));
+    let height = (lines.len() as u16 + 2).min(area.height.saturating_sub(2));
+    let popup = ratatui::layout::Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Thick)
+        .border_style(app.theme.accent)
+        .title(format!(" keys ({}) ", app.keymap.preset))
+        .title_bottom(Line::from(" any key: close ").alignment(Alignment::Right));
+    f.render_widget(Clear, popup);
+    f.render_widget(Paragraph::new(lines).block(block), popup);
+}
+
+fn render_main_content(
+    f: &mut ratatui::Frame, 
+    area: &ratatui::layout::Rect, 
+    app: &AppState, 
+    filtered_events: &[&GameEvent],
+    content_width: u16,
+    total_games_per_screen: usize,
+    targets: &mut Vec<(ratatui::layout::Rect, ClickTarget)>
+) {
+    if let Some(ref error) = app.error_message {
+        let error_msg = Paragraph::new(format!("error: {}", error))
+            .style(app.theme.error)
+            .alignment(Alignment::Center)
+            .block(Block::default());
+        f.render_widget(error_msg, *area);
+    } else if app.show_bracket {
+        render_bracket(f, area, app);
+    } else if app.events.is_empty()
+        && let Some(waiting) = app.delay_message() {
+        let waiting = Paragraph::new(waiting)
+            .style(app.theme.dim)
+            .alignment(Alignment::Center)
+            .block(Block::default());
+        f.render_widget(waiting, *area);
+    } else if filtered_events.is_empty() {
+        let no_games = Paragraph::new("no games found :c")
+            .style(app.theme.dim)
+            .alignment(Alignment::Center)
+            .block(Block::default());
+        f.render_widget(no_games, *area);
+    } else {
+        render_games(f, area, app, filtered_events, content_width, total_games_per_screen, targets);
+    }
+}
+
+fn render_games(
+    f: &mut ratatui::Frame,
+    area: &ratatui::layout::Rect,
+    app: &AppState,
+    filtered_events: &[&GameEvent],
+    content_width: u16,
+    total_games_per_screen: usize,
+    targets: &mut Vec<(ratatui::layout::Rect, ClickTarget)>
+) {
+    let boards = leaderboard_events(filtered_events);
+    if !boards.is_empty() {
+        render_leaderboards(f, area, app, &boards, targets);
+        return;
+    }
+
+    // always start on a row boundary, the column count changes with the width
+    let columns = app.calculate_grid_columns(content_width);
+    let start_game = (app.scroll_offset - app.scroll_offset % columns).min(filtered_events.len());
+    let end_game = (start_game + total_games_per_screen).min(filtered_events.len());
+    let visible_events = &filtered_events[start_game..end_game];
+
+    if app.layout_mode == LayoutMode::Compact {
+        render_compact_table(f, area, app, visible_events, targets);
+    } else if app.group_games {
+        render_grouped_games(f, area, app, visible_events, columns, targets);
     } else {
-        // fallback: just return the inning number
-        return format!("{}", status.period);
+        render_game_grid(f, area, app, visible_events, columns, targets);
+    }
+}
+
+// cards flow left to right, then top to bottom
+fn render_game_grid(
+    f: &mut ratatui::Frame,
+    area: &ratatui::layout::Rect,
+    app: &AppState,
+    visible_events: &[&GameEvent],
+    columns: usize,
+    targets: &mut Vec<(ratatui::layout::Rect, ClickTarget)>
+) {
+    let rows: Vec<&[&GameEvent]> = visible_events.chunks(columns).collect();
+
+    let row_areas = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints(
+            rows.iter()
+                .map(|_| Constraint::Length(6))
+                .chain(std::iter::once(Constraint::Min(0)))
+                .collect::<Vec<_>>()
+        )
+        .split(*area);
+
+    for (row_events, row_area) in rows.iter().zip(row_areas.iter()) {
+        render_row(f, row_area, app, row_events, columns, targets);
+    }
+}
+
+fn render_row(
+    f: &mut ratatui::Frame,
+    area: &ratatui::layout::Rect,
+    app: &AppState,
+    events: &[&GameEvent],
+    columns: usize,
+    targets: &mut Vec<(ratatui::layout::Rect, ClickTarget)>
+) {
+    let card_areas = Layout::default()
+        .direction(Direction::Horizontal)
+        .spacing(2)
+        .constraints((0..columns).map(|_| Constraint::Ratio(1, columns as u32)).collect::<Vec<_>>())
+        .split(*area);
+
+    for (event, card_area) in events.iter().zip(card_areas.iter()) {
+        let game_widget = app.format_game_widget(event);
+        f.render_widget(game_widget, *card_area);
+        targets.push((*card_area, ClickTarget::Game(event.id.clone())));
+    }
+}
+
+// header line plus the top and bottom margin of render_game_grid
+const GROUP_HEADER_HEIGHT: u16 = 3;
+
+fn render_grouped_games(
+    f: &mut ratatui::Frame,
+    area: &ratatui::layout::Rect,
+    app: &AppState,
+    visible_events: &[&GameEvent],
+    columns: usize,
+    targets: &mut Vec<(ratatui::layout::Rect, ClickTarget)>
+) {
+    let mut remaining = *area;
+
+    for group_events in visible_events.chunk_by(|a, b| GameGroup::of(a) == GameGroup::of(b)) {
+        if remaining.height == 0 {
+            break;
+        }
+
+        let group = GameGroup::of(group_events[0]);
+        let rows = group_events.len().div_ceil(columns) as u16;
+        let height = (GROUP_HEADER_HEIGHT + rows * 6).min(remaining.height);
+
+        let section = ratatui::layout::Rect { height, ..remaining };
+        let section_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(section);
+
+        let header = Paragraph::new(format!("── {} ──", group.label()))
+            .style(get_group_style(&app.theme, group))
+            .alignment(Alignment::Center);
+        f.render_widget(header, section_chunks[0]);
+        render_game_grid(f, &section_chunks[1], app, group_events, columns, targets);
+
+        remaining.y += height;
+        remaining.height -= height;
+    }
+}
+
+// top and bottom border plus the column header row
+const COMPACT_TABLE_CHROME: u16 = 3;
+
+fn render_compact_table(
+    f: &mut ratatui::Frame,
+    area: &ratatui::layout::Rect,
+    app: &AppState,
+    visible_events: &[&GameEvent],
+    targets: &mut Vec<(ratatui::layout::Rect, ClickTarget)>
+) {
+    let header = Row::new(["away", "score", "home", "status", "records", "tv"])
+        .style(app.theme.dim.add_modifier(Modifier::BOLD));
+
+    let mut rows = Vec::new();
+    let mut current_group = None;
+    // rows start below the top border and the column header
+    let first_row_y = area.y + 2;
+    for event in visible_events {
+        let group = GameGroup::of(event);
+        if app.group_games && current_group != Some(group) {
+            // the status column is the only one wide enough for the label
+            let label = Cell::from(format!("── {} ──", group.label()));
+            rows.push(Row::new([Cell::from(""), Cell::from(""), Cell::from(""), label]).style(get_group_style(&app.theme, group)));
+            current_group = Some(group);
+        }
+        let event_rows = app.format_game_row(event);
+        let row_area = ratatui::layout::Rect {
+            x: area.x + 1,
+            y: first_row_y + rows.len() as u16,
+            width: area.width.saturating_sub(2),
+            height: event_rows.len() as u16,
+        };
+        if row_area.bottom() < area.bottom() {
+            targets.push((row_area, ClickTarget::Game(event.id.clone())));
+        }
+        rows.extend(event_rows);
+    }
+
+    // room for a "#12 " or "(3) " before the team
+    let widths = [
+        Constraint::Length(9),
+        Constraint::Length(7),
+        Constraint::Length(9),
+        Constraint::Min(14),
+        Constraint::Length(19),
+        Constraint::Length(12),
+    ];
+    let table = Table::new(rows, widths)
+        .header(header)
+        .column_spacing(2)
+        .block(Block::default().borders(Borders::ALL).border_style(app.theme.border));
+    f.render_widget(table, *area);
+}
+
+// one table per tournament, scrolled a player at a time
+fn render_leaderboards(
+    f: &mut ratatui::Frame,
+    area: &ratatui::layout::Rect,
+    app: &AppState,
+    boards: &[&GameEvent],
+    targets: &mut Vec<(ratatui::layout::Rect, ClickTarget)>
+) {
+    let board_areas = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(boards.iter().map(|_| Constraint::Ratio(1, boards.len() as u32)).collect::<Vec<_>>())
+        .split(*area);
+
+    for (event, board_area) in boards.iter().zip(board_areas.iter()) {
+        let Some(competition) = leaderboard_competition(event) else {
+            continue;
+        };
+        let [first, second, third] = app.leaderboard_columns();
+        let header = Row::new(["pos", "player", first, second, third])
+            .style(app.theme.dim.add_modifier(Modifier::BOLD));
+        // the offset follows the longest board, the shorter ones stop at their last page
+        let entries = app.shown_leaderboard(event, competition);
+        let fits = board_area.height.saturating_sub(3) as usize;
+        let skip = app.scroll_offset.min(entries.len().saturating_sub(fits));
+        let rows: Vec<Row> = entries.into_iter()
+            .skip(skip)
+            .map(|entry| {
+                let player_style = if app.is_favorite_player(&entry.player) { app.theme.accent } else { app.theme.text };
+                let [first, second, third] = entry.standing.cells().map(str::to_string);
+                Row::new(vec![
+                    Cell::from(entry.position).style(app.theme.dim),
+                    Cell::from(entry.player).style(player_style),
+                    Cell::from(first).style(app.theme.score),
+                    Cell::from(second).style(app.theme.dim),
+                    Cell::from(third).style(app.theme.text),
+                ])
+            })
+            .collect();
+
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(app.card_border_style(event))
+            .title(format!(" {} ", app.format_leaderboard_title(event, competition)))
+            .title_style(get_group_style(&app.theme, GameGroup::of(event)));
+        if app.is_selected(event) {
+            block = block.border_type(BorderType::Thick);
+        }
+
+        let widths = [
+            Constraint::Length(4),
+            Constraint::Length(26),
+            Constraint::Length(9),
+            Constraint::Length(5),
+            Constraint::Min(5),
+        ];
+        let table = Table::new(rows, widths)
+            .header(header)
+            .column_spacing(2)
+            .block(block);
+        f.render_widget(table, *board_area);
+        targets.push((*board_area, ClickTarget::Game(event.id.clone())));
+    }
+}
+
+// two teams and a border
+const MATCHUP_HEIGHT: u16 = 4;
+const MAX_BRACKET_WIDTH: u16 = 30;
+
+// a column per stage, each matchup in a box of its own
+fn render_bracket(f: &mut ratatui::Frame, area: &ratatui::layout::Rect, app: &AppState) {
+    let Some(bracket) = app.bracket.as_deref().filter(|bracket| !bracket.is_empty()) else {
+        let (message, style) = match (&app.bracket, &app.bracket_error) {
+            _ if app.bracket_delayed() => ("no bracket while on a delay, it would show who went through".to_string(), app.theme.dim),
+            (None, Some(error)) => (format!("error: {}", error), app.theme.error),
+            (None, None) => ("loading bracket...".to_string(), app.theme.dim),
+            (Some(_), _) => ("no playoff series found :c".to_string(), app.theme.dim),
+        };
+        let empty = Paragraph::new(message)
+            .style(style)
+            .alignment(Alignment::Center)
+            .block(Block::default());
+        f.render_widget(empty, *area);
+        return;
+    };
+
+    let stages = bracket_stages(bracket);
+    let masked = app.hides_later_rounds(bracket);
+    let column_width = (area.width / stages.len() as u16).min(MAX_BRACKET_WIDTH);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(stages.iter().map(|_| Constraint::Length(column_width)).collect::<Vec<_>>())
+        .flex(Flex::Center)
+        .split(*area);
+
+    for (index, ((stage, matchups), column)) in stages.iter().zip(columns.iter()).enumerate() {
+        let [heading, body] = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(*column);
+        let heading_widget = Paragraph::new(stage.to_lowercase())
+            .style(app.theme.accent.add_modifier(Modifier::BOLD))
+            .alignment(Alignment::Center);
+        f.render_widget(heading_widget, heading);
+
+        // only columns too long for the screen scroll
+        let fits = calculate_bracket_rows(column.height);
+        let shown: Vec<&BracketSeries> = matchups.iter()
+            .skip(app.scroll_offset.min(matchups.len().saturating_sub(fits)))
+            .take(fits)
+            .copied()
+            .collect();
+
+        // spaced around the column, so a later round sits between the matchups that feed it
+        let box_height = (body.height / shown.len() as u16).min(MATCHUP_HEIGHT);
+        let slots = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(shown.iter().map(|_| Constraint::Length(box_height)).collect::<Vec<_>>())
+            .flex(Flex::SpaceAround)
+            .split(body);
+        for (series, slot) in shown.iter().zip(slots.iter()) {
+            render_matchup(f, slot, app, series, masked && index > 0);
+        }
+    }
+}
+
+// seed, team and wins for both sides. a masked matchup doesn't say who's in it either
+fn render_matchup(f: &mut ratatui::Frame, area: &ratatui::layout::Rect, app: &AppState, series: &BracketSeries, masked: bool) {
+    let hidden = masked || app.hides_series(series);
+    let decided = !hidden && series.teams.iter().any(|team| team.advanced);
+    let inner_width = area.width.saturating_sub(2) as usize;
+
+    let lines: Vec<Line> = series.teams.iter()
+        .map(|team| {
+            let style = match (decided, team.advanced) {
+                _ if masked => app.theme.dim,
+                (true, true) => app.team_style(&team.team, None).add_modifier(Modifier::BOLD),
+                (true, false) => app.theme.dim,
+                (false, _) => app.team_style(&team.team, None),
+            };
+            let wins_style = if decided && !team.advanced { app.theme.dim } else { app.theme.score };
+            let seed = team.seed.as_ref().filter(|_| !masked).map(|seed| format!("({})", seed)).unwrap_or_default();
+            let wins = if hidden { HIDDEN_SCORE.to_string() } else { team.wins.to_string() };
+            let name = if masked { HIDDEN_SCORE } else { team.team.abbreviation.as_str() };
+            let name_width = inner_width.saturating_sub(9).max(name.chars().count());
+            Line::from(vec![
+                Span::styled(format!("{:>4} ", seed), app.theme.dim),
+                Span::styled(format!("{:<name_width$}", name), style),
+                Span::styled(format!(" {:>3}", wins), wins_style),
+            ])
+        })
+        .collect();
+
+    let summary = series.summary.as_ref()
+        .filter(|_| !hidden)
+        .map(|summary| Line::from(Span::styled(format!(" {} ", summary), app.theme.dim)).alignment(Alignment::Center));
+    // the top border alone still keeps matchups apart
+    let block = match area.height {
+        0..=2 => Block::default(),
+        3 => Block::default().borders(Borders::TOP).border_style(app.theme.border),
+        _ => Block::default().borders(Borders::ALL).border_style(app.theme.border),
+    };
+    let block = match summary {
+        Some(summary) if area.height >= 4 => block.title_bottom(summary),
+        Some(summary) if area.height == 3 => block.title(summary),
+        _ => block,
+    };
+    f.render_widget(Paragraph::new(lines).block(block), *area);
+}
+
+fn get_group_style(theme: &Theme, group: GameGroup) -> Style {
+    match group {
+        GameGroup::Live => theme.live,
+        GameGroup::Final => theme.finished,
+        GameGroup::Upcoming => theme.upcoming,
+    }
+}
+
+fn render_footer(
+    f: &mut ratatui::Frame,
+    area: &ratatui::layout::Rect,
+    app: &AppState,
+    filtered_events: &[&GameEvent],
+    total_games_per_screen: usize
+) {
+    if app.search_active {
+        render_search_bar(f, area, app, filtered_events);
+        return;
+    }
+
+    let keys = &app.keymap;
+    let needs_scroll = app.scroll_total(filtered_events) > total_games_per_screen;
+    let time_left = app.time_until_next_refresh().as_secs();
+    let scroll_text = if needs_scroll {
+        format!("{} {} scroll | ", keys.hint(Action::ScrollUp), keys.hint(Action::ScrollDown))
+    } else {
+        String::new()
+    };
+    let filter_text = if app.search_query.is_empty() {
+        format!("{}: filter", keys.hint(Action::Search))
+    } else {
+        format!("/{} ({}: clear)", app.search_query, keys.hint(Action::Clear))
+    };
+    let league_text = if app.leagues.len() > 1 {
+        format!("{}: league | ", keys.hint(Action::NextLeague))
+    } else {
+        String::new()
+    };
+    let delay = app.delay.for_league(&app.selected_league);
+    let delay_text = if delay.is_zero() {
+        String::new()
+    } else {
+        format!(" | {} behind", format_delay(delay))
+    };
+    let bracket_text = if app.show_bracket {
+        format!("{}: games | ", keys.hint(Action::Bracket))
+    } else {
+        String::new()
+    };
+    let reveal_text = if app.spoilers.enabled {
+        format!("{}: reveal | ", keys.hint(Action::Reveal))
+    } else {
+        String::new()
+    };
+    let footer_text = format!(
+        "{}: quit | {} | {}: sort ({}) | {}{}{}{}{}: help | ↻ {}{}",
+        keys.hint(Action::Quit),
+        filter_text,
+        keys.hint(Action::Sort),
+        app.sort_mode.label(),
+        bracket_text,
+        league_text,
+        reveal_text,
+        scroll_text,
+        keys.hint(Action::Help),
+        time_left,
+        delay_text
+    );
+    
+    let footer = Paragraph::new(footer_text)
+        .style(app.theme.dim)
+        .alignment(Alignment::Center)
+        .block(Block::default());
+    f.render_widget(footer, *area);
+}
+
+fn render_search_bar(
+    f: &mut ratatui::Frame,
+    area: &ratatui::layout::Rect,
+    app: &AppState,
+    filtered_events: &[&GameEvent]
+) {
+    let search_line = Line::from(vec![
+        Span::styled("/", app.theme.accent.add_modifier(Modifier::BOLD)),
+        Span::styled(app.search_query.clone(), app.theme.text),
+        Span::styled("█", app.theme.dim),
+        Span::styled(
+            format!("  {} games | enter: done | esc: clear", filtered_events.len()),
+            app.theme.dim
+        ),
+    ]);
+
+    let search_bar = Paragraph::new(search_line)
+        .alignment(Alignment::Center)
+        .block(Block::default());
+    f.render_widget(search_bar, *area);
+}
+
+fn handle_search_input(key_code: KeyCode, app: &mut AppState) {
+    match key_code {
+        KeyCode::Esc => app.clear_search(),
+        KeyCode::Enter => app.finish_search(),
+        KeyCode::Backspace => app.pop_search_char(),
+        KeyCode::Char(c) => app.push_search_char(c),
+        _ => {}
+    }
+}
+
+async fn handle_input(key: KeyEvent, app: &mut AppState) -> Result<bool, Box<dyn Error>> {
+    // the search bar swallows keys while it's open
+    if app.search_active {
+        handle_search_input(key.code, app);
+        return Ok(false);
+    }
+
+    // any key closes the help
+    if app.show_help {
+        app.show_help = false;
+        return Ok(false);
+    }
+
+    let Some(action) = app.keymap.action(&key) else {
+        return Ok(false);
+    };
+    match action {
+        Action::Quit => return Ok(true), // exit
+        Action::Search => app.start_search(),
+        Action::Clear if app.detail_open => app.close_detail(),
+        Action::Clear => app.clear_search(),
+        Action::Refresh => {
+            // manual refresh
+            app.bracket = None;
+            if let Err(e) = app.fetch_data().await {
+                app.error_message = Some(format!("refresh failed: {}", e));
+            }
+        }
+        Action::Sort => app.cycle_sort_mode(),
+        Action::Headers => app.toggle_grouping(),
+        Action::Layout => app.toggle_layout(),
+        Action::Log => app.toggle_log(),
+        Action::LogUp => app.scroll_log_up(),
+        Action::LogDown => app.scroll_log_down(),
+        Action::ScrollUp => app.scroll_up(),
+        Action::ScrollDown => app.scroll_down(),
+        Action::PageUp => app.scroll_page_up(),
+        Action::PageDown => app.scroll_page_down(),
+        Action::Top => app.scroll_to_top(),
+        Action::Bottom => app.scroll_to_bottom(),
+        Action::SelectPrevious => app.move_selection(-1),
+        Action::SelectNext => app.move_selection(1),
+        Action::Details => app.open_detail(),
+        Action::Reveal => app.toggle_reveal(),
+        Action::Bracket => {
+            app.toggle_bracket();
+            app.load_bracket().await;
+        }
+        Action::NextLeague | Action::PreviousLeague => {
+            let step = if action == Action::NextLeague { 1 } else { -1 };
+            if app.cycle_league(step)
+                && let Err(e) = app.fetch_data().await {
+                app.error_message = Some(format!("refresh failed: {}", e));
+            }
+        }
+        Action::Help => app.show_help = true,
+    }
+    Ok(false)
+}
+
+async fn handle_mouse(mouse: MouseEvent, app: &mut AppState) {
+    let target = app.click_target(mouse.column, mouse.row);
+    match mouse.kind {
+        MouseEventKind::ScrollUp if target == Some(ClickTarget::Log) => app.scroll_log_up(),
+        MouseEventKind::ScrollDown if target == Some(ClickTarget::Log) => app.scroll_log_down(),
+        MouseEventKind::ScrollUp if !app.detail_open => app.scroll_up(),
+        MouseEventKind::ScrollDown if !app.detail_open => app.scroll_down(),
+        // any click dismisses the help or detail popup
+        MouseEventKind::Down(MouseButton::Left) if app.show_help => app.show_help = false,
+        MouseEventKind::Down(MouseButton::Left) if app.detail_open => app.close_detail(),
+        MouseEventKind::Down(MouseButton::Left) => match target {
+            Some(ClickTarget::Game(event_id)) => app.click_game(&event_id),
+            Some(ClickTarget::League(index)) => {
+                if app.select_league(index)
+                    && let Err(e) = app.fetch_data().await {
+                    app.error_message = Some(format!("refresh failed: {}", e));
+                }
+            }
+            _ => {}
+        },
+        _ => {}
+    }
+}
+
+fn cleanup_terminal(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<(), Box<dyn Error>> {
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+    Ok(())
+}
+
+// ticker
+
+// one column per step, roughly a tv crawl
+const TICKER_STEP: Duration = Duration::from_millis(150);
+// golfers shown per tournament
+const TICKER_LEADERS: usize = 5;
+
+impl AppState {
+    fn ticker_spans(&self) -> Vec<Span<'static>> {
+        let mut spans = Vec::new();
+        for event in self.get_filtered_events() {
+            for competition in shown_competitions(event) {
+                let score = match away_home(competition) {
+                    Some((away, home)) if self.is_fight() => format!("{} vs {} ", away.short_name(), home.short_name()),
+                    Some((away, home)) => format!("{} {}-{} {} ", away.short_name(), self.shown_score(event, away), self.shown_score(event, home), home.short_name()),
+                    None => format!("{} {} ", event.short_name, self.format_leaders(event, competition, TICKER_LEADERS)),
+                };
+                spans.push(Span::styled(score, self.theme.score));
+                spans.push(Span::styled(
+                    self.format_bar_status(competition),
+                    get_group_style(&self.theme, GameGroup::of(event))
+                ));
+                spans.push(Span::styled("   •   ", self.theme.dim));
+            }
+        }
+        spans
+    }
+}
+
+// a width-wide window into the looping ticker text, starting at offset
+fn ticker_window(spans: &[Span<'static>], offset: usize, width: usize, theme: &Theme) -> Line<'static> {
+    let cells: Vec<(char, Style)> = spans.iter()
+        .flat_map(|span| span.content.chars().map(move |c| (c, span.style)))
+        .collect();
+    if cells.is_empty() {
+        return Line::from(Span::styled("no games found :c", theme.dim));
+    }
+
+    // short enough to fit, no need to scroll
+    if cells.len() <= width {
+        return Line::from(spans.to_vec());
+    }
+
+    let mut window: Vec<Span<'static>> = Vec::new();
+    let mut current = String::new();
+    let mut current_style = cells[offset % cells.len()].1;
+    for i in 0..width {
+        let (c, style) = cells[(offset + i) % cells.len()];
+        if style != current_style {
+            window.push(Span::styled(std::mem::take(&mut current), current_style));
+            current_style = style;
+        }
+        current.push(c);
+    }
+    window.push(Span::styled(current, current_style));
+    Line::from(window)
+}
+
+fn render_ticker_line(f: &mut ratatui::Frame, app: &AppState, offset: usize) {
+    let area = f.area();
+    // a thin pane shows just the line, anything taller gets it centered
+    let line_area = ratatui::layout::Rect {
+        y: area.y + area.height.saturating_sub(1) / 2,
+        height: area.height.min(1),
+        ..area
+    };
+    let line = match (&app.error_message, app.delay_message()) {
+        (Some(error), _) if app.events.is_empty() => Line::from(Span::styled(format!("error: {}", error), app.theme.error)),
+        (_, Some(waiting)) if app.events.is_empty() => Line::from(Span::styled(waiting, app.theme.dim)),
+        _ => ticker_window(&app.ticker_spans(), offset, line_area.width as usize, &app.theme),
+    };
+    f.render_widget(Paragraph::new(line), line_area);
+}
+
+// inline keeps the normal screen and redraws a single line in place
+async fn render_ticker(app: &mut AppState, inline: bool) -> Result<(), Box<dyn Error>> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    let mut terminal = if inline {
+        Terminal::with_options(CrosstermBackend::new(stdout), TerminalOptions { viewport: Viewport::Inline(1) })?
+    } else {
+        execute!(stdout, EnterAlternateScreen)?;
+        Terminal::new(CrosstermBackend::new(stdout))?
+    };
+    terminal.hide_cursor()?;
+
+    let started = Instant::now();
+    loop {
+        if app.should_refresh() && !app.is_refreshing
+            && let Err(e) = app.fetch_data().await {
+            app.error_message = Some(format!("refresh failed: {}", e));
+        }
+        app.release_delayed();
+
+        let offset = (started.elapsed().as_millis() / TICKER_STEP.as_millis()) as usize;
+        terminal.draw(|f| render_ticker_line(f, app, offset))?;
+
+        if event::poll(TICKER_STEP)?
+            && let Event::Key(key) = event::read()? {
+            match app.keymap.action(&key) {
+                Some(Action::Quit | Action::Clear) => break,
+                Some(Action::Refresh) => {
+                    if let Err(e) = app.fetch_data().await {
+                        app.error_message = Some(format!("refresh failed: {}", e));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    disable_raw_mode()?;
+    if inline {
+        terminal.clear()?;
+    } else {
+        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    }
+    terminal.show_cursor()?;
+    Ok(())
+}
+
+
+// plain text output
+
+impl AppState {
+    fn format_game_text(&self, event: &GameEvent) -> Vec<[String; 3]> {
+        shown_competitions(event).into_iter()
+            .flat_map(|competition| {
+                if away_home(competition).is_none() {
+                    return self.format_leaderboard_text(event, competition);
+                }
+                let mut rows: Vec<[String; 3]> = self.format_competition_text(event, competition).into_iter().collect();
+                // goals and red cards on a line of their own
+                let highlights: Vec<String> = match_events(competition).iter()
+                    .filter(|match_event| match_event.kind != MatchEventKind::YellowCard)
+                    .map(|match_event| format!("{} {}", match_event.symbol(), match_event.label()))
+                    .collect();
+                if !highlights.is_empty() && !self.hides_score(event) {
+                    rows.push([String::new(), String::new(), highlights.join(", ")]);
+                }
+                rows
+            })
+            .collect()
+    }
+
+    fn format_competition_text(&self, event: &GameEvent, competition: &Competition) -> Option<[String; 3]> {
+        let (away, home) = away_home(competition)?;
+        let score_line = if self.is_tennis() {
+            let sets = if self.hides_score(event) { HIDDEN_SCORE.to_string() } else { format_set_scores(away, home) };
+            format!("{}{} {} {}{}", format_rank(away), away.short_name(), sets, format_rank(home), home.short_name())
+        } else if self.is_fight() {
+            let winner = [away, home].into_iter()
+                .find(|competitor| competitor.winner && !self.hides_score(event))
+                .map(|competitor| format!(" ({} wins)", competitor.short_name()))
+                .unwrap_or_default();
+            format!("{} vs {}{}", away.short_name(), home.short_name(), winner)
+        } else {
+            format!(
+                "{} {} - {} {}",
+                away.short_name(),
+                self.shown_score(event, away),
+                self.shown_score(event, home),
+                home.short_name()
+            )
+        };
+        let status_line = plain_status(&self.format_status(competition));
+        let record_line = self.format_series_line(event, competition)
+            .or_else(|| self.shown_records(event, competition))
+            .or_else(|| match_heading(event, competition))
+            .unwrap_or_default();
+        Some([score_line, status_line, record_line])
     }
-}
 
-fn format_soccer_status(status: &Status) -> String {
-    if status.period == 1 {
-        format!("{}' 1H", status.display_clock)
-    } else if status.period == 2 {
-        format!("{}' 2H", status.display_clock)
-    } else {
-        format!("{}' ET", status.display_clock)
+    // the tournament, then a row per player: position and name, to par, thru and today
+    fn format_leaderboard_text(&self, event: &GameEvent, competition: &Competition) -> Vec<[String; 3]> {
+        let title = [leaderboard_heading(event, competition), plain_status(&self.format_status(competition)), String::new()];
+        std::iter::once(title)
+            .chain(self.shown_leaderboard(event, competition).into_iter().map(|entry| [
+                format!("{:<4} {}", entry.position, entry.player),
+                entry.standing.cells()[0].to_string(),
+                format!("{:<3} {}", entry.standing.cells()[1], entry.standing.cells()[2]),
+            ]))
+            .collect()
     }
-}
 
-// ui layout
+    fn format_scoreboard_text(&self) -> String {
+        let rows: Vec<[String; 3]> = self.get_filtered_events().iter()
+            .flat_map(|event| self.format_game_text(event))
+            .collect();
 
-impl AppState {
-    fn calculate_games_per_screen(&self, content_width: u16, content_height: u16) -> usize {
-        let can_fit_two_columns = content_width >= 80;
-        let games_per_column = (content_height / 6).max(1) as usize; // roughly 6 lines per boxed game
-        
-        if can_fit_two_columns {
-            games_per_column * 2
-        } else {
-            games_per_column
+        let title = match &self.team_filter {
+            Some(team) => format!("scrbrd | {}", team.to_lowercase()),
+            None => format!("scrbrd | {}", league_name(&self.selected_league)),
+        };
+        if rows.is_empty() {
+            return format!("{}\nno games found :c\n", title);
+        }
+
+        // pad columns so scores and statuses line up
+        let score_width = rows.iter().map(|row| row[0].chars().count()).max().unwrap_or(0);
+        let status_width = rows.iter().map(|row| row[1].chars().count()).max().unwrap_or(0);
+        let mut output = format!("{}\n", title);
+        for [score, status, records] in rows {
+            let line = format!("{:<score_width$}  {:<status_width$}  {}", score, status, records);
+            output.push_str(line.trim_end());
+            output.push('\n');
         }
+        output
     }
 }
 
-// ui render
-
-async fn render_scoreboard(app: &mut AppState) -> Result<(), Box<dyn Error>> {
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
+// the live dot only makes sense in the tui
+fn plain_status(status: &str) -> String {
+    status.trim_start_matches("🔴 ").to_string()
+}
 
-    loop {
-        // check if we need to auto-refresh
-        if app.should_refresh() && !app.is_refreshing {
-            if let Err(e) = app.fetch_data().await {
-                app.error_message = Some(format!("refresh failed: {}", e));
-            }
-        }
+// machine-readable output
 
-        terminal.draw(|f| {
-            let chunks = create_main_layout(f.area());
-            let filtered_events = app.get_filtered_events();
-            let content_width = chunks[1].width;
-            let content_height = chunks[1].height;
-            let total_games_per_screen = app.calculate_games_per_screen(content_width, content_height);
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Text,
+    Json,
+    Ndjson,
+}
 
-            // render header
-            render_header(f, &chunks[0], app);
+impl OutputFormat {
+    fn parse(value: &str) -> Result<Self, Box<dyn Error>> {
+        match value.to_lowercase().as_str() {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "ndjson" => Ok(OutputFormat::Ndjson),
+            _ => Err(format!("unsupported format: {} (text, json, ndjson)", value).into()),
+        }
+    }
+}
 
-            // render main content
-            render_main_content(f, &chunks[1], app, &filtered_events, content_width, total_games_per_screen);
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct GameSummary {
+    id: String,
+    league: String,
+    name: String,
+    state: String,
+    status: String,
+    detail: String,
+    start: Option<String>,
+    period: u32,
+    clock: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    away: Option<TeamSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    home: Option<TeamSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tournament: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    round: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    session: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    series: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    leaderboard: Vec<LeaderboardEntry>,
+    broadcasts: Vec<String>,
+}
 
-            // render footer
-            render_footer(f, &chunks[2], app, &filtered_events, total_games_per_screen);
-        })?;
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct TeamSummary {
+    abbreviation: String,
+    name: String,
+    score: String,
+    record: Option<String>,
+    // games per set, in tennis
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    sets: Vec<u32>,
+}
 
-        // handle input with timeout for refresh checking
-        if event::poll(Duration::from_millis(500))? {
-            if let Event::Key(key) = event::read()? {
-                if handle_input(key.code, app).await? {
-                    break; // exit requested
-                }
-            }
+impl TeamSummary {
+    // a hidden game keeps its teams, like the tui, but not the score or the records that move with it
+    fn from_competitor(competitor: &Competitor, hidden: bool) -> Self {
+        if hidden {
+            return Self {
+                abbreviation: competitor.short_name().to_string(),
+                name: competitor.display_name().to_string(),
+                score: HIDDEN_SCORE.to_string(),
+                record: None,
+                sets: Vec::new(),
+            };
+        }
+        Self {
+            abbreviation: competitor.short_name().to_string(),
+            name: competitor.display_name().to_string(),
+            score: competitor.score.clone(),
+            record: competitor.records.first().map(|r| r.summary.clone()),
+            sets: set_games(competitor),
         }
     }
-    
-    cleanup_terminal(&mut terminal)?;
-    Ok(())
 }
 
-fn create_main_layout(area: ratatui::layout::Rect) -> Vec<ratatui::layout::Rect> {
-    Layout::default()
-        .direction(Direction::Vertical)
-        .margin(1)
-        .constraints([
-            Constraint::Length(3),
-            Constraint::Min(8),
-            Constraint::Length(3),
-        ].as_ref())
-        .split(area).to_vec()
+#[derive(Debug, Serialize)]
+struct GameUpdate<'a> {
+    event: &'a str,
+    #[serde(flatten)]
+    game: &'a GameSummary,
 }
 
-fn render_header(f: &mut ratatui::Frame, area: &ratatui::layout::Rect, app: &AppState) {
-    let title = match &app.team_filter {
-        Some(team) => format!("scrbrd | {}", team.to_lowercase()),
-        None => format!("scrbrd | {}", app.selected_league.to_lowercase()),
+impl AppState {
+    fn summarize_games(&self) -> Vec<GameSummary> {
+        self.get_filtered_events().iter()
+            .flat_map(|event| {
+                shown_competitions(event).into_iter().map(|competition| {
+                    let teams = away_home(competition);
+                    let status = &competition.status;
+                    let hidden = self.hides_score(event);
+                    GameSummary {
+                        id: event.id.clone(),
+                        league: self.selected_league.to_lowercase(),
+                        name: event.short_name.clone(),
+                        state: status.status_type.state.clone(),
+                        status: self.format_status_brief(competition),
+                        detail: self.shown_detail(event, competition, &status.status_type.short_detail),
+                        start: parse_event_date(&competition.date).map(|start| start.to_rfc3339()),
+                        period: status.period,
+                        clock: status.display_clock.clone(),
+                        away: teams.map(|(away, _)| TeamSummary::from_competitor(away, hidden)),
+                        home: teams.map(|(_, home)| TeamSummary::from_competitor(home, hidden)),
+                        tournament: event.tournament.clone(),
+                        round: competition.round.as_ref().map(|round| round.display_name.clone()),
+                        session: session_name(competition),
+                        series: series_summary(competition).filter(|_| !hidden),
+                        leaderboard: if teams.is_none() { self.shown_leaderboard(event, competition) } else { Vec::new() },
+                        broadcasts: competition.broadcasts.iter()
+                            .flat_map(|broadcast| broadcast.names.clone())
+                            .collect(),
+                    }
+                })
+            })
+            .collect()
+    }
+
+    // like format_status, but without the ticking countdown so it only changes with the game
+    fn format_status_brief(&self, competition: &Competition) -> String {
+        let status = &competition.status;
+        match status.status_type.state.as_str() {
+            "in" => self.format_live_status(competition),
+            "pre" if status.status_type.name == "STATUS_SCHEDULED" => parse_event_date(&competition.date)
+                .map(|start| self.format_kickoff(start))
+                .unwrap_or_else(|| status.status_type.short_detail.clone()),
+            _ => plain_status(&self.format_status(competition)),
+        }
+    }
+}
+
+async fn print_scoreboard(app: &mut AppState, format: OutputFormat) -> Result<(), Box<dyn Error>> {
+    app.events = app.fetch_delayed_events().await?;
+    let output = match format {
+        OutputFormat::Text => app.format_scoreboard_text(),
+        OutputFormat::Json => format!("{}\n", serde_json::to_string_pretty(&app.summarize_games())?),
+        OutputFormat::Ndjson => app.summarize_games().iter()
+            .map(|game| serde_json::to_string(&GameUpdate { event: "snapshot", game }).map(|line| line + "\n"))
+            .collect::<Result<String, _>>()?,
     };
-    
-    let header = Paragraph::new(title)
-        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
-        .alignment(Alignment::Center) 
-        .block(Block::default());
-    f.render_widget(header, *area);
+    write_stdout(&output)?;
+    Ok(())
 }
 
-fn render_main_content(
-    f: &mut ratatui::Frame, 
-    area: &ratatui::layout::Rect, 
-    app: &AppState, 
-    filtered_events: &[&GameEvent],
-    content_width: u16,
-    total_games_per_screen: usize
-) {
-    if let Some(ref error) = app.error_message {
-        let error_msg = Paragraph::new(format!("error: {}", error))
-            .style(Style::default().fg(Color::Red))
-            .alignment(Alignment::Center)
-            .block(Block::default());
-        f.render_widget(error_msg, *area);
-    } else if filtered_events.is_empty() {
-        let no_games = Paragraph::new("no games found :c")
-            .style(Style::default().fg(Color::Gray))
-            .alignment(Alignment::Center)
-            .block(Block::default());
-        f.render_widget(no_games, *area);
-    } else {
-        render_games(f, area, app, filtered_events, content_width, total_games_per_screen);
+// a closed pipe (i.e. `| head`) just means nobody is listening anymore
+fn write_stdout(output: &str) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+    match stdout.write_all(output.as_bytes()).and_then(|_| stdout.flush()) {
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        result => result,
     }
 }
 
-fn render_games(
-    f: &mut ratatui::Frame,
-    area: &ratatui::layout::Rect,
-    app: &AppState,
-    filtered_events: &[&GameEvent],
-    content_width: u16,
-    total_games_per_screen: usize
-) {
-    let start_game = app.scroll_offset;
-    let end_game = (start_game + total_games_per_screen).min(filtered_events.len());
-    let visible_events = &filtered_events[start_game..end_game];
-    let can_fit_two_columns = content_width >= 80;
+// one line per game whenever it changes, starting with a snapshot of every game
+async fn watch_scoreboard(app: &mut AppState, format: OutputFormat) -> Result<(), Box<dyn Error>> {
+    let mut previous: HashMap<String, GameSummary> = HashMap::new();
+    let mut first = true;
+    app.notify.without_terminal();
 
-    if can_fit_two_columns && visible_events.len() > 1 {
-        render_two_column_layout(f, area, app, visible_events);
-    } else {
-        render_single_column_layout(f, area, app, visible_events);
+    loop {
+        match app.next_update().await {
+            Ok(None) => {}
+            Ok(Some(changes)) => {
+                let mut output = String::new();
+                match format {
+                    OutputFormat::Ndjson => {
+                        for game in app.summarize_games() {
+                            if previous.get(&game.id) == Some(&game) {
+                                continue;
+                            }
+                            let hidden = app.events.iter().any(|event| event.id == game.id && app.hides_score(event));
+                            let event = if first { "snapshot" } else { change_label(&changes, &game.id, hidden) };
+                            output.push_str(&serde_json::to_string(&GameUpdate { event, game: &game })?);
+                            output.push('\n');
+                            previous.insert(game.id.clone(), game);
+                        }
+                    }
+                    OutputFormat::Json => output = format!("{}\n", serde_json::to_string(&app.summarize_games())?),
+                    OutputFormat::Text => output = format!("{}\n", app.format_scoreboard_text()),
+                }
+                // stop once the reader goes away
+                let mut stdout = io::stdout().lock();
+                if stdout.write_all(output.as_bytes()).and_then(|_| stdout.flush()).is_err() {
+                    return Ok(());
+                }
+                first = false;
+            }
+            Err(e) => eprintln!("refresh failed: {}", e),
+        }
+
+        tokio::time::sleep(app.time_until_next_update().max(Duration::from_secs(1))).await;
     }
 }
 
-fn render_two_column_layout(
-    f: &mut ratatui::Frame,
-    area: &ratatui::layout::Rect,
-    app: &AppState,
-    visible_events: &[&GameEvent]
-) {
-    let game_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(*area);
-    
-    // split games between columns
-    let left_events: Vec<_> = visible_events.iter()
-        .enumerate()
-        .filter(|(i, _)| i % 2 == 0)
-        .map(|(_, event)| *event)
-        .collect();
-    
-    let right_events: Vec<_> = visible_events.iter()
-        .enumerate()
-        .filter(|(i, _)| i % 2 == 1)
-        .map(|(_, event)| *event)
+// the most significant change for a game, "update" for anything else (clock, inning, ...).
+// a hidden game's score changes are only updates
+fn change_label(changes: &[GameChange], event_id: &str, hidden: bool) -> &'static str {
+    let kinds: Vec<ChangeKind> = changes.iter()
+        .filter(|change| change.event_id == event_id)
+        .filter(|change| !hidden || !matches!(change.kind, ChangeKind::Score | ChangeKind::LeadChange))
+        .map(|change| change.kind)
         .collect();
-    
-    render_column(f, &game_chunks[0], app, &left_events);
-    render_column(f, &game_chunks[1], app, &right_events);
+    [
+        (ChangeKind::Final, "final"),
+        (ChangeKind::Start, "start"),
+        (ChangeKind::LeadChange, "lead-change"),
+        (ChangeKind::Score, "score"),
+    ]
+    .into_iter()
+    .find(|(kind, _)| kinds.contains(kind))
+    .map(|(_, label)| label)
+    .unwrap_or("update")
 }
 
-fn render_single_column_layout(
-    f: &mut ratatui::Frame,
-    area: &ratatui::layout::Rect,
-    app: &AppState,
-    visible_events: &[&GameEvent]
-) {
-    render_column(f, area, app, visible_events);
+// status bar output
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BarStyle {
+    Plain,
+    Waybar,
+    Polybar,
+    I3blocks,
 }
 
-fn render_column(
-    f: &mut ratatui::Frame,
-    area: &ratatui::layout::Rect,
-    app: &AppState,
-    events: &[&GameEvent]
-) {
-    let layout = Layout::default()
-        .direction(Direction::Vertical)
-        .margin(1)
-        .constraints(
-            events.iter()
-                .map(|_| Constraint::Length(6))
-                .chain(std::iter::once(Constraint::Min(0)))
-                .collect::<Vec<_>>()
-        )
-        .split(*area);
-    
-    for (i, event) in events.iter().enumerate() {
-        let game_widget = app.format_game_widget(event);
-        f.render_widget(game_widget, layout[i]);
+impl BarStyle {
+    fn parse(value: &str) -> Result<Self, Box<dyn Error>> {
+        match value.to_lowercase().as_str() {
+            "plain" | "tmux" => Ok(BarStyle::Plain),
+            "waybar" => Ok(BarStyle::Waybar),
+            "polybar" => Ok(BarStyle::Polybar),
+            "i3blocks" => Ok(BarStyle::I3blocks),
+            _ => Err(format!("unsupported bar style: {} (plain, waybar, polybar, i3blocks)", value).into()),
+        }
     }
 }
 
-fn render_footer(
-    f: &mut ratatui::Frame,
-    area: &ratatui::layout::Rect,
-    app: &AppState,
-    filtered_events: &[&GameEvent],
-    total_games_per_screen: usize
-) {
-    let needs_scroll = filtered_events.len() > total_games_per_screen;
-    let time_left = app.time_until_next_refresh().as_secs();
-    let scroll_text = if needs_scroll { "↑ ↓ scroll | " } else { "" };
-    let footer_text = format!("q: quit | {}↻ {}", scroll_text, time_left);
-    
-    let footer = Paragraph::new(footer_text)
-        .style(Style::default().fg(Color::Gray))
-        .alignment(Alignment::Center)
-        .block(Block::default());
-    f.render_widget(footer, *area);
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+struct BarConfig {
+    template: String,
+    separator: String,
 }
 
-async fn handle_input(key_code: KeyCode, app: &mut AppState) -> Result<bool, Box<dyn Error>> {
-    match key_code {
-        KeyCode::Char('q') => Ok(true), // exit
-        KeyCode::Char('r') => {
-            // manual refresh
-            if let Err(e) = app.fetch_data().await {
-                app.error_message = Some(format!("refresh failed: {}", e));
-            }
-            Ok(false)
+impl Default for BarConfig {
+    fn default() -> Self {
+        Self {
+            template: "{away} {away_score}-{home_score} {home} {status}".to_string(),
+            separator: " | ".to_string(),
         }
-        KeyCode::Up => {
-            app.scroll_up();
-            Ok(false)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct WaybarOutput {
+    text: String,
+    tooltip: String,
+    class: &'static str,
+}
+
+impl AppState {
+    // favorites when there are any, otherwise whatever -t and the sort leave
+    fn bar_events(&self) -> Vec<&GameEvent> {
+        let events = self.get_filtered_events();
+        if self.favorites.is_empty() {
+            events
+        } else {
+            events.into_iter().filter(|event| self.is_favorite(event)).collect()
         }
-        KeyCode::Down => {
-            app.scroll_down();
-            Ok(false)
+    }
+
+    fn format_bar_game(&self, event: &GameEvent, template: &str) -> Option<String> {
+        let competition = current_competition(event)?;
+        // the template is for matchups, tournaments just show the leader
+        if away_home(competition).is_none() {
+            return Some(format!("{} {} {}", event.short_name, self.format_leaders(event, competition, 1), self.format_bar_status(competition)));
         }
-        _ => Ok(false),
+        let (away, home) = away_home(competition)?;
+        Some(template
+            .replace("{away}", away.short_name())
+            .replace("{home}", home.short_name())
+            .replace("{away_score}", self.shown_score(event, away))
+            .replace("{home_score}", self.shown_score(event, home))
+            .replace("{status}", &self.format_bar_status(competition)))
     }
-}
 
-fn cleanup_terminal(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<(), Box<dyn Error>> {
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
-    Ok(())
+    fn format_bar_status(&self, competition: &Competition) -> String {
+        let status = &competition.status;
+        match status.status_type.state.as_str() {
+            "in" => self.format_live_status(competition),
+            "post" if status.status_type.completed => "F".to_string(),
+            "pre" if status.status_type.name == "STATUS_SCHEDULED" => parse_event_date(&competition.date)
+                .map(|start| self.format_short_kickoff(start))
+                .unwrap_or_else(|| status.status_type.short_detail.clone()),
+            _ => status.status_type.short_detail.clone(),
+        }
+    }
+
+    fn format_bar(&self, style: BarStyle, bar: &BarConfig) -> Result<String, Box<dyn Error>> {
+        let events = self.bar_events();
+        let games: Vec<(GameGroup, String)> = events.iter()
+            .filter_map(|event| Some((GameGroup::of(event), self.format_bar_game(event, &bar.template)?)))
+            .collect();
+        let text = games.iter()
+            .map(|(_, game)| game.as_str())
+            .collect::<Vec<_>>()
+            .join(&bar.separator);
+
+        // live beats upcoming beats final for the bar's overall state
+        let class = match games.iter().map(|(group, _)| *group).min() {
+            Some(GameGroup::Live) => "live",
+            Some(GameGroup::Upcoming) => "pre",
+            Some(GameGroup::Final) => "final",
+            None => "none",
+        };
+
+        let output = match style {
+            BarStyle::Plain => text,
+            BarStyle::Waybar => {
+                let tooltip = events.iter()
+                    .map(|event| self.format_event_summary(event))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                serde_json::to_string(&WaybarOutput { text, tooltip, class })?
+            }
+            BarStyle::Polybar => games.iter()
+                .map(|(group, game)| match group {
+                    GameGroup::Live => format!("%{{F#ff5555}}{}%{{F-}}", game),
+                    _ => game.clone(),
+                })
+                .collect::<Vec<_>>()
+                .join(&bar.separator),
+            BarStyle::I3blocks => {
+                // full text, short text and color lines
+                let short_text = games.first().map(|(_, game)| game.clone()).unwrap_or_default();
+                let color = match class {
+                    "live" => "#ff5555",
+                    "final" => "#50fa7b",
+                    _ => "#f1fa8c",
+                };
+                format!("{}\n{}\n{}", text, short_text, color)
+            }
+        };
+        Ok(output)
+    }
 }
 
+async fn print_bar(app: &mut AppState, style: BarStyle, bar: &BarConfig, watch: bool) -> Result<(), Box<dyn Error>> {
+    if !watch {
+        app.events = app.fetch_delayed_events().await?;
+        write_stdout(&format!("{}\n", app.format_bar(style, bar)?))?;
+        return Ok(());
+    }
+
+    // waybar and friends read one line per update, and nothing else
+    app.notify.without_terminal();
+    loop {
+        match app.next_update().await {
+            Ok(None) => {}
+            Ok(Some(_)) => {
+                let mut stdout = io::stdout().lock();
+                let line = format!("{}\n", app.format_bar(style, bar)?);
+                if stdout.write_all(line.as_bytes()).and_then(|_| stdout.flush()).is_err() {
+                    return Ok(());
+                }
+            }
+            Err(e) => eprintln!("refresh failed: {}", e),
+        }
+        tokio::time::sleep(app.time_until_next_update().max(Duration::from_secs(1))).await;
+    }
+}
 
 // main
 
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
-    let matches = Command::new("scrbrd")
+    let mut command = Command::new("scrbrd")
         .version("0.2.0")
         .author("Chuck Swung")
         .about("a tui sports tracker for real-time scores and status")
@@ -637,8 +5356,8 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 .short('l')
                 .long("league")
                 .value_name("LEAGUE")
-                .help("supported leagues: mlb, nba, wnba, nfl, nhl, mls, nwsl, premier")
-                .required(true)
+                .help(league_help())
+                .global(true)
         )
         .arg(
             Arg::new("team")
@@ -646,17 +5365,201 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 .long("team")
                 .value_name("TEAM")
                 .help("filter by team name, without city (i.e. guardians)")
+                .global(true)
+        )
+        .arg(
+            Arg::new("config")
+                .short('c')
+                .long("config")
+                .value_name("PATH")
+                .help(format!(
+                    "config file (default: {})",
+                    default_config_path().map_or("none".to_string(), |path| path.display().to_string())
+                ))
+                .global(true)
+        )
+        .arg(
+            Arg::new("tz")
+                .long("tz")
+                .value_name("TIMEZONE")
+                .help("timezone for start times, i.e. america/los_angeles or europe/london (default: local)")
+                .global(true)
+        )
+        .arg(
+            Arg::new("once")
+                .long("once")
+                .action(ArgAction::SetTrue)
+                .help("print the scoreboard as plain text and exit")
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .value_name("FORMAT")
+                .help("print instead of the tui: text, json or ndjson")
+        )
+        .arg(
+            Arg::new("watch")
+                .long("watch")
+                .action(ArgAction::SetTrue)
+                .help("keep printing updates every refresh, i.e. --watch --format ndjson")
+                .global(true)
+        )
+        .arg(
+            Arg::new("layout")
+                .long("layout")
+                .value_name("LAYOUT")
+                .help("cards or compact (default: cards)")
+        )
+        .arg(
+            Arg::new("conference")
+                .long("conference")
+                .value_name("CONFERENCE")
+                .help("college leagues only, i.e. sec, big-ten, acc, big-12, fcs (default: every fbs or division i game)")
+                .global(true)
         )
-        .get_matches();
+        .arg(
+            Arg::new("top25")
+                .long("top25")
+                .action(ArgAction::SetTrue)
+                .help("only games with an ap top 25 team")
+                .global(true)
+        )
+        .arg(
+            Arg::new("delay")
+                .long("delay")
+                .value_name("DELAY")
+                .help("show every update this far behind live, i.e. 45s to match a stream")
+                .global(true)
+        )
+        .arg(
+            Arg::new("no-spoilers")
+                .long("no-spoilers")
+                .action(ArgAction::SetTrue)
+                .help("hide scores and results, for every game or the [spoilers] teams and leagues")
+                .global(true)
+        )
+        .arg(
+            Arg::new("theme")
+                .long("theme")
+                .value_name("THEME")
+                .help("dark, light, high-contrast, monochrome or one from the config (default: dark)")
+        )
+        .arg(
+            Arg::new("ticker")
+                .long("ticker")
+                .action(ArgAction::SetTrue)
+                .help("show every game on a single scrolling line")
+        )
+        .arg(
+            Arg::new("inline")
+                .long("inline")
+                .action(ArgAction::SetTrue)
+                .requires("ticker")
+                .help("draw the ticker in place on the current line instead of full screen")
+        )
+        .subcommand(
+            Command::new("bar")
+                .about("print a one-line summary for tmux, waybar, polybar or i3blocks")
+                .arg(
+                    Arg::new("style")
+                        .short('s')
+                        .long("style")
+                        .value_name("STYLE")
+                        .help("plain, waybar, polybar or i3blocks (default: plain)")
+                )
+                .arg(
+                    Arg::new("template")
+                        .long("template")
+                        .value_name("TEMPLATE")
+                        .help("per game, with {away} {home} {away_score} {home_score} {status}")
+                )
+                .arg(
+                    Arg::new("separator")
+                        .long("separator")
+                        .value_name("SEPARATOR")
+                        .help("between games (default: \" | \")")
+                )
+        );
+    let matches = command.get_matches_mut();
 
-    let league = matches.get_one::<String>("league").unwrap().to_string();
+    // clap won't let a global arg be required, so the usage error is raised here instead
+    let Some(league) = matches.get_one::<String>("league").map(|s| s.to_string()) else {
+        command.error(ErrorKind::MissingRequiredArgument, "a league is required, i.e. scrbrd -l mlb").exit();
+    };
+    // several leagues become tabs in the tui, everything else shows the first one
+    let leagues: Vec<String> = league.split(',')
+        .map(|league| league.trim())
+        .filter(|league| !league.is_empty())
+        .map(|league| find_league(league).map(|info| info.slug.to_string()))
+        .collect::<Result<_, _>>()?;
+    if leagues.is_empty() {
+        return Err("a league is required, i.e. scrbrd -l mlb".into());
+    }
+    let conference = matches.get_one::<String>("conference").cloned();
+    if let Some(ref conference) = conference {
+        if !leagues.iter().any(|league| is_college_league(league)) {
+            return Err("--conference only applies to college leagues, i.e. -l college-football".into());
+        }
+        for league in &leagues {
+            college_group(league, Some(conference))?;
+        }
+    }
     let team = matches.get_one::<String>("team").map(|s| s.to_string());
+    let mut config = Config::load(matches.get_one::<String>("config").map(Path::new))?;
+    let timezone = match matches.get_one::<String>("tz").or(config.tz.as_ref()) {
+        Some(tz) => TimeZoneSetting::parse(tz)?,
+        None => TimeZoneSetting::Local,
+    };
+
+    if let Some(layout) = matches.get_one::<String>("layout") {
+        config.layout = LayoutMode::parse(layout)?;
+    }
+    if matches.get_flag("no-spoilers") {
+        config.spoilers.enabled = true;
+    }
+
+    let theme = Theme::from_config(&config, matches.get_one::<String>("theme").map(|s| s.as_str()))?;
+    let keymap = Keymap::from_config(&config.keys)?;
+    let delay = StreamDelay::from_config(config.delay.as_ref(), matches.get_one::<String>("delay").map(|s| s.as_str()))?;
+    let mut app = AppState::new(leagues, team, timezone, theme, keymap, delay, &config);
+    app.conference = conference;
+    app.top25 = matches.get_flag("top25");
+
+    if let Some(bar_matches) = matches.subcommand_matches("bar") {
+        let style = match bar_matches.get_one::<String>("style") {
+            Some(style) => BarStyle::parse(style)?,
+            None => BarStyle::Plain,
+        };
+        let mut bar = config.bar.clone();
+        if let Some(template) = bar_matches.get_one::<String>("template") {
+            bar.template = template.clone();
+        }
+        if let Some(separator) = bar_matches.get_one::<String>("separator") {
+            bar.separator = separator.clone();
+        }
+        return print_bar(&mut app, style, &bar, matches.get_flag("watch")).await;
+    }
+
+    let format = matches.get_one::<String>("format")
+        .map(|format| OutputFormat::parse(format))
+        .transpose()?;
+    if matches.get_flag("watch") {
+        return watch_scoreboard(&mut app, format.unwrap_or(OutputFormat::Ndjson)).await;
+    }
+    if matches.get_flag("once") || format.is_some() {
+        return print_scoreboard(&mut app, format.unwrap_or(OutputFormat::Text)).await;
+    }
 
-    let mut app = AppState::new(league, team);
+    if matches.get_flag("ticker") {
+        if let Err(e) = app.fetch_data().await {
+            app.error_message = Some(e.to_string());
+        }
+        return render_ticker(&mut app, matches.get_flag("inline")).await;
+    }
 
     // retch initial data
     match app.fetch_data().await {
-        Ok(()) => {},
+        Ok(_) => {},
         Err(e) => {
             app.error_message = Some(e.to_string());
         }